@@ -2,6 +2,11 @@
 
 use crate::utils::divmod;
 
+const FILE_SIZE_BINARY: [&str; 9] = [
+    "bytes", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB",
+];
+const FILE_SIZE_DECIMAL: [&str; 9] = ["bytes", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
 /// Formats a number of seconds as a clock time, \[H:\]MM:SS and SSs.
 pub fn interval(seconds: usize, human: bool) -> String {
     if human && seconds < 60 {
@@ -38,6 +43,63 @@ pub fn size_of(num: f64, divisor: f64) -> String {
     format!("{:3.1}Y", value)
 }
 
+/// Returns unit (divisor) and suffix suitable for formatting given number of bytes.
+///
+/// If `binary` is `true`, units are powers of 1024 (`KiB`, `MiB`, ...) else powers of 1000 (`kB`, `MB`, ...).
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::file_size_unit(4_500_000.0, false), (1_000_000.0, "MB"));
+/// assert_eq!(format::file_size_unit(4_500_000.0, true), (1_048_576.0, "MiB"));
+/// ```
+pub fn file_size_unit(size: f64, binary: bool) -> (f64, &'static str) {
+    let (base, suffixes) = if binary {
+        (1024., FILE_SIZE_BINARY)
+    } else {
+        (1000., FILE_SIZE_DECIMAL)
+    };
+    let mut unit = 1.;
+
+    for suffix in &suffixes[..(suffixes.len() - 1)] {
+        if size < unit * base {
+            return (unit, suffix);
+        }
+
+        unit *= base;
+    }
+
+    (unit, suffixes[suffixes.len() - 1])
+}
+
+/// Formats a number of bytes as a file size, like `1.2 MB` or `1.2 MiB`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::file_size(1.0, false), "1 byte");
+/// assert_eq!(format::file_size(999.0, false), "999 bytes");
+/// assert_eq!(format::file_size(1_234_567.0, false), "1.2 MB");
+/// assert_eq!(format::file_size(1_234_567.0, true), "1.2 MiB");
+/// ```
+pub fn file_size(size: f64, binary: bool) -> String {
+    let (unit, suffix) = file_size_unit(size, binary);
+
+    if unit == 1. {
+        if size as u64 == 1 {
+            "1 byte".to_owned()
+        } else {
+            format!("{} {}", size as u64, suffix)
+        }
+    } else {
+        format!("{:.1} {}", size / unit, suffix)
+    }
+}
+
 /// Formats seconds as a clock time, SSs | MMmin | Hhr | Ddays.
 pub fn time(seconds: f64) -> String {
     let mut value = seconds;
@@ -9,13 +9,21 @@ pub mod format;
 pub mod term;
 
 pub use crate::std::{
-    monitor, Animation, Bar, BarBuilder, BarExt, BarIter, Colour, RowManager, TqdmIterator,
+    monitor, Animation, Bar, BarBuilder, BarExt, BarIter, Colour, Marquee, RowManager, Segment,
+    SteadyTick, Tick, TqdmIterator, Truncate, WeightedBarIter,
 };
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use kdam_derive::BarExt;
 
+#[doc(hidden)]
+pub mod __derive {
+    pub use crate::std::derive::{
+        clear, input, refresh, reset, update, update_to, write, write_to, Hooks,
+    };
+}
+
 #[cfg(feature = "notebook")]
 #[cfg_attr(docsrs, doc(cfg(feature = "notebook")))]
 pub use crate::std::set_notebook;
@@ -33,7 +41,9 @@ mod rich;
 
 #[cfg(feature = "rich")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rich")))]
-pub use rich::{Column, RichProgress};
+pub use rich::{
+    Column, ProgressColumn, ProgressColumnClone, RichGroup, RichProgress, RichTheme, StyledText,
+};
 
 #[cfg(feature = "spinner")]
 mod spinner;
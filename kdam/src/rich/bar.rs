@@ -1,5 +1,5 @@
-use super::styles;
-use crate::{std::Bar, term::Colorizer, BarExt};
+use super::{column, ProgressColumn, RichTheme};
+use crate::{format, std::Bar, term::Colorizer, BarExt, Marquee, Truncate};
 use std::num::{NonZeroI16, NonZeroU16};
 
 #[cfg(feature = "spinner")]
@@ -16,20 +16,73 @@ pub enum Column {
     Count,
     /// Progress formatted counter display i.e. `counter/total`.
     CountTotal,
+    /// User defined column display.
+    ///
+    /// See [ProgressColumn](crate::ProgressColumn) for an example.
+    Custom(Box<dyn ProgressColumn>),
+    /// Downloaded and total file size display, like `1.2/4.5 MB`.
+    ///
+    /// Binary units (`KiB`, `MiB`, ...) are used if [unit_divisor](crate::Bar::unit_divisor) is `1024`.
+    Download,
     /// Progress elapsed time display.
     ElapsedTime,
+    /// Downloaded file size display, like `1.2 MB`.
+    ///
+    /// Binary units (`KiB`, `MiB`, ...) are used if [unit_divisor](crate::Bar::unit_divisor) is `1024`.
+    FileSize,
+    /// Custom text display, scrolled within a fixed width if it is wider than [Marquee::width](crate::Marquee::width).
+    ///
+    /// Text style can be specified in the same way as [Column::Text](Self::Text).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{Column, Marquee};
+    ///
+    /// Column::Marquee("[cyan]/home/user/videos/2024/holidays/beach.mp4".to_owned(), Marquee::new(20, 5.0));
+    /// ```
+    Marquee(String, Marquee),
+    /// Progress counter display with a separator, like `12/100` where total width is used to align counter.
+    /// Only counter is displayed, if progress is indefinite i.e. `total` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Column;
+    ///
+    /// Column::MofN("/".to_owned());
+    /// Column::MofN(" of ".to_owned());
+    /// ```
+    MofN(String),
     /// Progress percentage done (with precision) display.
     Percentage(usize),
     /// Progress update rate display.
     Rate,
     /// Progress remaining time (ETA) display.
     RemainingTime,
+    /// Progress remaining time (ETA) display, which displays elapsed time once progress is completed.
+    RemainingOrElapsedTime,
+    /// Sparkline of recent update rates display with given width, like `▁▂▃▅▇▇▅`.
+    Sparkline(usize),
     /// Custom spinners display.
     #[cfg(feature = "spinner")]
     #[cfg_attr(docsrs, doc(cfg(feature = "spinner")))]
     Spinner(Spinner),
+    /// Column display with a different theme than [RichProgress::theme](RichProgress::theme).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{Column, RichTheme};
+    ///
+    /// Column::Themed(Box::new(Column::Animation), Box::new(RichTheme::ascii()));
+    /// ```
+    Themed(Box<Column>, Box<RichTheme>),
     /// Custom text display.
     ///
+    /// Text is styled using rich markup if [markup](crate::Bar::markup) is `true`,
+    /// else text style can be specified using a leading `[style]` tag, which is applied to whole text.
+    ///
     /// # Example
     ///
     /// ```
@@ -37,10 +90,19 @@ pub enum Column {
     ///
     /// Column::Text("•".to_owned());
     /// Column::Text("[bold red]Downloading".to_owned());
+    /// Column::Text("[bold]Downloading[/] [cyan]file.txt[/cyan]".to_owned());
     /// ```
     Text(String),
     /// Progress total display.
     Total,
+    /// Total file size display, like `4.5 MB`.
+    ///
+    /// Binary units (`KiB`, `MiB`, ...) are used if [unit_divisor](crate::Bar::unit_divisor) is `1024`.
+    TotalFileSize,
+    /// Download speed display, like `1.2 MB/s`.
+    ///
+    /// Binary units (`KiB/s`, `MiB/s`, ...) are used if [unit_divisor](crate::Bar::unit_divisor) is `1024`.
+    TransferSpeed,
 }
 
 /// An implementation [rich.progress](https://rich.readthedocs.io/en/latest/progress.html) using [Bar](crate::Bar).
@@ -61,17 +123,35 @@ pub enum Column {
 ///
 /// eprintln!();
 /// ```
-#[derive(BarExt, Debug)]
+#[derive(BarExt, Clone, Debug)]
 pub struct RichProgress {
     pub columns: Vec<Column>,
     #[bar]
     pub pb: Bar,
+    pub theme: RichTheme,
 }
 
 impl RichProgress {
     /// Create a new [RichProgress](Self).
-    pub fn new(pb: Bar, columns: Vec<Column>) -> Self {
-        Self { columns, pb }
+    ///
+    /// If [truncate](crate::Bar::truncate) of `pb` is left to default,
+    /// then all [Column::Text](Column::Text) columns are truncated (from left to right) when display exceeds terminal width.
+    pub fn new(mut pb: Bar, columns: Vec<Column>) -> Self {
+        if pb.truncate == Truncate::defaults() {
+            pb.truncate.extend(
+                columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, col)| matches!(unthemed(col), Column::Text(_)))
+                    .map(|(index, _)| Truncate::Column(index)),
+            );
+        }
+
+        Self {
+            columns,
+            pb,
+            theme: RichTheme::default(),
+        }
     }
 
     /// Replace a column at specific index.
@@ -86,91 +166,50 @@ impl RichProgress {
 
     /// Render progress bar text.
     pub fn render(&mut self) -> String {
+        self.pb.elapsed_time();
         let mut bar_text = vec![];
         let mut bar_length = 0;
         let mut progress_bar_index = None;
 
-        for col in self.columns.iter() {
-            match col {
-                Column::Animation => {
-                    progress_bar_index = Some(bar_text.len());
-                    bar_text.push(String::new());
-                }
+        let mut progress_bar_theme = &self.theme;
 
-                Column::Count => {
-                    let fmt_progress = self.pb.fmt_counter();
-                    bar_length += fmt_progress.len();
-                    bar_text.push(fmt_progress.colorize("green"));
-                }
-
-                Column::CountTotal => {
-                    let fmt_progress = format!("{}/{}", self.pb.fmt_counter(), self.pb.fmt_total());
-                    bar_length += fmt_progress.len();
-                    bar_text.push(fmt_progress.colorize("green"));
-                }
-
-                Column::ElapsedTime => {
-                    let elapsed_time = self.pb.fmt_elapsed_time();
-                    bar_length += elapsed_time.len();
-                    bar_text.push(elapsed_time.colorize("cyan"));
-                }
-
-                Column::Percentage(precision) => {
-                    let percentage = format!("{:.1$}%", self.pb.percentage() * 100., precision);
-                    bar_length += percentage.len();
-                    bar_text.push(percentage.colorize("magenta"));
-                }
-
-                Column::Rate => {
-                    let rate = self.pb.fmt_rate();
-                    bar_length += rate.len();
-                    bar_text.push(rate.colorize("red"));
-                }
-
-                Column::RemainingTime => {
-                    let remaining_time = self.pb.fmt_remaining_time();
-                    bar_length += remaining_time.len();
-                    bar_text.push(remaining_time.colorize("cyan"));
-                }
+        for col in self.columns.iter() {
+            if let Some((text, length)) = render_column(&self.pb, col, &self.theme) {
+                bar_length += length;
+                bar_text.push(text);
+            } else {
+                progress_bar_index = Some(bar_text.len());
+                progress_bar_theme = column_theme(col, &self.theme);
+                bar_text.push(String::new());
+            }
+        }
 
-                #[cfg(feature = "spinner")]
-                Column::Spinner(spinner) => {
-                    let frame = spinner.render_frame(self.pb.elapsed_time());
-                    bar_length += frame.chars().count();
-                    bar_text.push(frame.colorize("green"));
-                }
+        bar_length += bar_text.len() - 1;
 
-                Column::Text(text) => {
-                    let (color, text_start_index) = match (text.find('['), text.find(']')) {
-                        (Some(start), Some(end)) => {
-                            if start == 0 {
-                                (text.get(1..end), end + 1)
-                            } else {
-                                (None, 0)
-                            }
-                        }
-                        _ => (None, 0),
-                    };
-
-                    if let Some(code) = color {
-                        let text = &text[text_start_index..];
-                        bar_length += text.len_ansi();
-                        bar_text.push(text.colorize(code));
-                    } else {
-                        bar_length += text.len_ansi();
-                        bar_text.push(text.to_owned());
-                    }
-                }
+        let mut texts = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, col)| matches!(unthemed(col), Column::Text(_)))
+            .map(|(index, _)| (Truncate::Column(index), bar_text[index].clone()))
+            .collect::<Vec<_>>();
+        let animation_length = match self.pb.ncols {
+            Some(ncols) if progress_bar_index.is_some() => ncols as usize,
+            _ => 0,
+        };
 
-                Column::Total => {
-                    let fmt_progress = self.pb.fmt_total();
-                    bar_length += fmt_progress.len();
-                    bar_text.push(fmt_progress.colorize("green"));
+        if self
+            .pb
+            .truncate_to_fit(bar_length + animation_length, &mut texts)
+        {
+            for (part, text) in texts {
+                if let Truncate::Column(index) = part {
+                    bar_length -= bar_text[index].len_ansi() - text.len_ansi();
+                    bar_text[index] = text;
                 }
             }
         }
 
-        bar_length += bar_text.len() - 1;
         let mut ncols = 0;
 
         if let Some(progress_bar_index) = progress_bar_index {
@@ -180,14 +219,7 @@ impl RichProgress {
                 let _ = bar_text.remove(progress_bar_index);
             } else {
                 *bar_text.get_mut(progress_bar_index).unwrap() =
-                    if self.pb.indefinite() || !self.pb.started() {
-                        styles::pulse(
-                            NonZeroI16::new(ncols as i16).unwrap(),
-                            self.pb.elapsed_time(),
-                        )
-                    } else {
-                        styles::bar(NonZeroU16::new(ncols).unwrap(), self.pb.percentage())
-                    };
+                    render_animation(&self.pb, progress_bar_theme, ncols);
             }
         }
 
@@ -195,3 +227,148 @@ impl RichProgress {
         bar_text.join(" ")
     }
 }
+
+// "[bold red]text" -> (Some("bold red"), "text")
+fn split_style(text: &str) -> (Option<&str>, &str) {
+    match (text.find('['), text.find(']')) {
+        (Some(0), Some(end)) => (text.get(1..end), &text[(end + 1)..]),
+        _ => (None, text),
+    }
+}
+
+/// Returns text of a text column and its style.
+///
+/// If markup is enabled, text is rendered from rich markup else a leading `[style]` tag is used as style of whole text.
+fn styled_text<'a>(pb: &Bar, text: &'a str) -> (String, &'a str) {
+    if pb.markup {
+        (text.markup(), "")
+    } else {
+        let (code, text) = split_style(text);
+        (text.to_owned(), code.unwrap_or_default())
+    }
+}
+
+/// Render a column and returns its rendered text and display length.
+///
+/// Returns `None`, if column is [Column::Animation](Column::Animation) which is rendered after all other columns.
+pub(super) fn render_column(pb: &Bar, col: &Column, theme: &RichTheme) -> Option<(String, usize)> {
+    let (text, style) = match col {
+        Column::Animation => return None,
+        Column::Count => (pb.fmt_counter(), theme.count.as_str()),
+        Column::CountTotal => (
+            format!("{}/{}", pb.fmt_counter(), pb.fmt_total()),
+            theme.count.as_str(),
+        ),
+        Column::Custom(col) => {
+            let styled_text = column::render(col.as_ref(), pb);
+            let length = styled_text.text.len_ansi();
+            return Some((styled_text.render(), length));
+        }
+        Column::Download => {
+            let binary = pb.unit_divisor == 1024;
+            let download = if pb.indefinite() {
+                format::file_size(pb.counter as f64, binary)
+            } else {
+                let (unit, suffix) = format::file_size_unit(pb.total as f64, binary);
+                let precision = if unit == 1. { 0 } else { 1 };
+                format!(
+                    "{:.3$}/{:.3$} {}",
+                    pb.counter as f64 / unit,
+                    pb.total as f64 / unit,
+                    suffix,
+                    precision
+                )
+            };
+            (download, theme.count.as_str())
+        }
+        Column::ElapsedTime => (pb.fmt_elapsed_time(), theme.elapsed.as_str()),
+        Column::FileSize => (
+            format::file_size(pb.counter as f64, pb.unit_divisor == 1024),
+            theme.count.as_str(),
+        ),
+        Column::Marquee(text, marquee) => {
+            let (text, style) = styled_text(pb, text);
+            (marquee.render(&text, pb.last_elapsed_time()), style)
+        }
+        Column::MofN(separator) => {
+            let mofn = if pb.indefinite() {
+                pb.counter.to_string()
+            } else {
+                let total = pb.total.to_string();
+                format!("{:>3$}{}{}", pb.counter, separator, total, total.len())
+            };
+            (mofn, theme.count.as_str())
+        }
+        Column::Percentage(precision) => (
+            format!("{:.1$}%", pb.percentage() * 100., precision),
+            theme.percentage.as_str(),
+        ),
+        Column::Rate => (pb.fmt_rate(), theme.rate.as_str()),
+        Column::RemainingTime => (pb.fmt_remaining_time(), theme.remaining.as_str()),
+        Column::RemainingOrElapsedTime => {
+            if pb.completed() {
+                (pb.fmt_elapsed_time(), theme.remaining_finished.as_str())
+            } else {
+                (pb.fmt_remaining_time(), theme.remaining.as_str())
+            }
+        }
+        Column::Sparkline(width) => (pb.fmt_sparkline(*width), theme.rate.as_str()),
+        #[cfg(feature = "spinner")]
+        Column::Spinner(spinner) => (
+            spinner.render_frame(pb.last_elapsed_time()),
+            theme.spinner.as_str(),
+        ),
+        Column::Text(text) => styled_text(pb, text),
+        Column::Themed(col, theme) => return render_column(pb, col, theme),
+        Column::Total => (pb.fmt_total(), theme.count.as_str()),
+        Column::TotalFileSize => {
+            let total_file_size = if pb.indefinite() {
+                String::new()
+            } else {
+                format::file_size(pb.total as f64, pb.unit_divisor == 1024)
+            };
+            (total_file_size, theme.count.as_str())
+        }
+        Column::TransferSpeed => {
+            let speed = if pb.started() {
+                format::file_size(pb.rate() as f64, pb.unit_divisor == 1024) + "/s"
+            } else {
+                "?".to_owned()
+            };
+            (speed, theme.rate.as_str())
+        }
+    };
+
+    let length = text.len_ansi();
+    Some((text.colorize(style), length))
+}
+
+/// Render animation of `ncols` width, which must be greater than zero.
+///
+/// If `total = 0` or progress is not started, a pulsating animation is rendered else a normal animation is rendered.
+pub(super) fn render_animation(pb: &Bar, theme: &RichTheme, ncols: u16) -> String {
+    if pb.indefinite() || !pb.started() {
+        theme.render_pulse(
+            NonZeroI16::new(ncols as i16).unwrap(),
+            pb.last_elapsed_time(),
+        )
+    } else {
+        theme.render_bar(NonZeroU16::new(ncols).unwrap(), pb.percentage())
+    }
+}
+
+// Column::Themed(Column::Text, theme) -> Column::Text
+pub(super) fn unthemed(col: &Column) -> &Column {
+    match col {
+        Column::Themed(col, _) => unthemed(col),
+        _ => col,
+    }
+}
+
+// Column::Themed(Column::Animation, theme) -> theme
+pub(super) fn column_theme<'a>(col: &'a Column, theme: &'a RichTheme) -> &'a RichTheme {
+    match col {
+        Column::Themed(col, theme) => column_theme(col, theme),
+        _ => theme,
+    }
+}
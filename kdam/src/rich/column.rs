@@ -0,0 +1,127 @@
+use crate::{std::Bar, term::Colorizer};
+use std::fmt::Debug;
+
+/// Text with an optional style (e.g. "bold red", "#00ff00") rendered by a [ProgressColumn](ProgressColumn).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyledText {
+    pub text: String,
+    pub style: Option<String>,
+}
+
+impl StyledText {
+    /// Create a new [StyledText](Self) without any style.
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            style: None,
+        }
+    }
+
+    /// Create a new [StyledText](Self) with given style.
+    pub fn styled<T: Into<String>, S: Into<String>>(text: T, style: S) -> Self {
+        Self {
+            text: text.into(),
+            style: Some(style.into()),
+        }
+    }
+
+    /// Apply style to text.
+    pub fn render(&self) -> String {
+        if let Some(style) = &self.style {
+            self.text.colorize(style)
+        } else {
+            self.text.clone()
+        }
+    }
+}
+
+impl From<String> for StyledText {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+/// User defined column for [RichProgress](crate::RichProgress).
+///
+/// Implementors should also implement [Clone](Clone) and [Debug](Debug).
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, Bar, Column, ProgressColumn, RichProgress, StyledText};
+///
+/// #[derive(Clone, Debug)]
+/// struct Failed(usize);
+///
+/// impl ProgressColumn for Failed {
+///     fn render(&self, _bar: &Bar) -> StyledText {
+///         StyledText::styled(format!("{} failed", self.0), "bold red")
+///     }
+///
+///     fn min_width(&self) -> Option<usize> {
+///         Some(10)
+///     }
+/// }
+///
+/// let pb = RichProgress::new(
+///     tqdm!(total = 100),
+///     vec![Column::Animation, Column::Custom(Box::new(Failed(2)))]
+/// );
+/// ```
+pub trait ProgressColumn: Debug + Send + Sync + ProgressColumnClone {
+    /// Render column text.
+    fn render(&self, bar: &Bar) -> StyledText;
+
+    /// Minimum display width of column, rendered text is padded with spaces upto this width.
+    fn min_width(&self) -> Option<usize> {
+        None
+    }
+
+    /// Maximum display width of column, rendered text is truncated upto this width.
+    fn max_width(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Helper trait for cloning boxed [ProgressColumn](ProgressColumn) trait objects.
+///
+/// It is implemented for all types which implements [ProgressColumn](ProgressColumn) and [Clone](Clone).
+pub trait ProgressColumnClone {
+    fn clone_box(&self) -> Box<dyn ProgressColumn>;
+}
+
+impl<T: 'static + ProgressColumn + Clone> ProgressColumnClone for T {
+    fn clone_box(&self) -> Box<dyn ProgressColumn> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn ProgressColumn> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+pub(super) fn render(column: &dyn ProgressColumn, bar: &Bar) -> StyledText {
+    let mut styled_text = column.render(bar);
+
+    if let Some(max_width) = column.max_width() {
+        styled_text.text = styled_text.text.truncate_ansi(max_width);
+    }
+
+    if let Some(min_width) = column.min_width() {
+        let length = styled_text.text.len_ansi();
+
+        if length < min_width {
+            styled_text.text += &" ".repeat(min_width - length);
+        }
+    }
+
+    styled_text
+}
@@ -0,0 +1,263 @@
+use super::{
+    Column, RichProgress,
+    bar::{column_theme, render_animation, render_column, unthemed},
+};
+use crate::{BarExt, std::derive, term::Colorizer};
+use std::{io::Result, mem};
+
+/// Group of [RichProgress](crate::RichProgress) tasks displayed as a table.
+///
+/// Each column is aligned across tasks with the same column layout (same column variants in the same order)
+/// by padding it upto the widest text rendered in that column
+/// and all [Column::Animation](crate::Column::Animation) columns share the same width.
+/// Tasks are displayed one below the other in the order they are added.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, Column, RichGroup, RichProgress};
+///
+/// let columns = |name: &str| {
+///     vec![
+///         Column::Text(format!("[bold blue]{}", name)),
+///         Column::Animation,
+///         Column::Percentage(1),
+///         Column::CountTotal,
+///     ]
+/// };
+///
+/// let mut group = RichGroup::new();
+/// let first = group
+///     .add_task(RichProgress::new(tqdm!(total = 100), columns("first")))
+///     .unwrap();
+/// let second = group
+///     .add_task(RichProgress::new(tqdm!(total = 1000), columns("second task")))
+///     .unwrap();
+///
+/// for _ in 0..100 {
+///     group.update(first, 1).unwrap();
+///     group.update(second, 10).unwrap();
+/// }
+///
+/// eprint!("{}", "\n".repeat(group.len()));
+/// ```
+#[derive(Debug, Default)]
+pub struct RichGroup {
+    tasks: Vec<RichProgress>,
+}
+
+impl RichGroup {
+    // -----------------------------------------------------------------------------------------
+    // Constructors
+    // -----------------------------------------------------------------------------------------
+
+    /// Create a new empty [RichGroup](Self).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // -----------------------------------------------------------------------------------------
+    // Methods
+    // -----------------------------------------------------------------------------------------
+
+    /// Add a task at the bottom of group returning back it's index.
+    ///
+    /// Position of task is overwritten by group.
+    pub fn add_task(&mut self, mut task: RichProgress) -> Result<usize> {
+        task.pb.position = self.tasks.len() as u16;
+        self.tasks.push(task);
+        self.refresh()?;
+        Ok(self.tasks.len() - 1)
+    }
+
+    /// Returns a mutable reference to task.
+    ///
+    /// Call [refresh](Self::refresh) after modifying task to display changes.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut RichProgress> {
+        self.tasks.get_mut(index)
+    }
+
+    /// Returns number of tasks in group.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if group has no tasks.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Removes a task and returns it.
+    /// Tasks below the removed task are moved up and indexes of them are decremented by one.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn remove_task(&mut self, index: usize) -> Result<RichProgress> {
+        self.clear()?;
+        let task = self.tasks.remove(index);
+
+        for (position, task) in self.tasks.iter_mut().enumerate() {
+            task.pb.position = position as u16;
+        }
+
+        self.refresh()?;
+        Ok(task)
+    }
+
+    /// Increment counter of task by `n` and refresh whole group if task needs to be refreshed.
+    ///
+    /// Returns `true` if group is refreshed.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn update(&mut self, index: usize, n: usize) -> Result<bool> {
+        let task = &mut self.tasks[index];
+        let counter = task.pb.counter + n;
+        derive::advance(task, counter, n);
+        let should_refresh = task.pb.should_refresh();
+
+        if should_refresh {
+            self.refresh()?;
+        }
+
+        Ok(should_refresh)
+    }
+
+    /// Clear all tasks from terminal.
+    pub fn clear(&mut self) -> Result<()> {
+        for task in self.tasks.iter_mut() {
+            task.pb.clear()?;
+        }
+
+        Ok(())
+    }
+
+    /// Render and print all tasks.
+    pub fn refresh(&mut self) -> Result<()> {
+        for task in self.tasks.iter_mut() {
+            derive::before_group_render(&mut task.pb)?;
+        }
+
+        let texts = self.render();
+
+        for (task, mut text) in self.tasks.iter_mut().zip(texts) {
+            let length = text.len_ansi();
+
+            // Overwrite leftovers of previous render.
+            if length < task.pb.bar_length as usize {
+                text += &" ".repeat(task.pb.bar_length as usize - length);
+            }
+
+            task.pb.bar_length = length as u16;
+            task.pb.writer.print_at(task.pb.position, text.as_bytes())?;
+            derive::after_group_render(&task.pb, &text);
+        }
+
+        Ok(())
+    }
+
+    /// Render text of all tasks with aligned columns.
+    pub fn render(&mut self) -> Vec<String> {
+        for task in self.tasks.iter_mut() {
+            task.pb.elapsed_time();
+        }
+
+        let rows = self
+            .tasks
+            .iter()
+            .map(|task| {
+                task.columns
+                    .iter()
+                    .map(|col| render_column(&task.pb, col, &task.theme))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Columns are only aligned across tasks with the same layout.
+        let layouts = self
+            .tasks
+            .iter()
+            .map(|task| {
+                task.columns
+                    .iter()
+                    .map(|col| mem::discriminant(unthemed(col)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let widths = layouts
+            .iter()
+            .map(|layout| {
+                let mut widths = vec![0; layout.len()];
+
+                for (row, _) in rows.iter().zip(&layouts).filter(|(_, x)| *x == layout) {
+                    for (width, cell) in widths.iter_mut().zip(row) {
+                        if let Some((_, length)) = cell {
+                            *width = (*width).max(*length);
+                        }
+                    }
+                }
+
+                widths
+            })
+            .collect::<Vec<_>>();
+
+        // Display length of widest task excluding animation.
+        let bar_length = rows
+            .iter()
+            .zip(&widths)
+            .map(|(row, widths)| {
+                row.iter()
+                    .zip(widths)
+                    .filter_map(|(cell, width)| cell.as_ref().map(|_| width))
+                    .sum::<usize>()
+                    + row.len().saturating_sub(1)
+            })
+            .max()
+            .unwrap_or(0) as u16;
+
+        let ncols = self
+            .tasks
+            .iter_mut()
+            .zip(&rows)
+            .filter(|(_, row)| row.iter().any(Option::is_none))
+            .map(|(task, _)| task.pb.ncols_for_animation(bar_length))
+            .min()
+            .unwrap_or(0);
+
+        self.tasks
+            .iter_mut()
+            .zip(rows)
+            .zip(&widths)
+            .map(|((task, row), widths)| {
+                let mut cells = vec![];
+
+                for ((col, cell), width) in task.columns.iter().zip(row).zip(widths) {
+                    match cell {
+                        Some((text, length)) => {
+                            let padding = " ".repeat(width - length);
+
+                            if matches!(
+                                unthemed(col),
+                                Column::Custom(_) | Column::Marquee(..) | Column::Text(_)
+                            ) {
+                                cells.push(text + &padding);
+                            } else {
+                                cells.push(padding + &text);
+                            }
+                        }
+                        None if ncols == 0 => (),
+                        None => cells.push(render_animation(
+                            &task.pb,
+                            column_theme(col, &task.theme),
+                            ncols,
+                        )),
+                    }
+                }
+
+                cells.join(" ")
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,9 @@
 mod bar;
+mod column;
+mod group;
 mod styles;
 
 pub use bar::{Column, RichProgress};
+pub use column::{ProgressColumn, ProgressColumnClone, StyledText};
+pub use group::RichGroup;
+pub use styles::RichTheme;
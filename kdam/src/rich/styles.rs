@@ -1,53 +1,154 @@
 use std::num::{NonZeroU16, NonZeroI16};
 use crate::term::Colorizer;
 
-// Characters
-const BAR_CHR: &str = "━";
-const BAR_END_CHR: &str = "╸";
-
-// Colours
-const BAR_COLOUR: &str = "#F92672";
-const BAR_COMPLETED_COLOUR: &str = "#729c1f";
-const BAR_PULSE_COLOURS: [&str; 18] = [
-    "#3a3a3a", "#3e393b", "#4c383f", "#613545", "#7b334d", "#b72c5e", "#d12a66", "#e6276c",
-    "#f42670", "#f92672", "#f42670", "#e6276c", "#d12a66", "#b72c5e", "#993056", "#7b334d",
-    "#613545", "#4c383f",
-];
-const BAR_UNCOMPLETED_COLOUR: &str = "#525252";
-
-pub(super) fn bar(ncols: NonZeroU16, progress: f32) -> String {
-    assert!((0.0..=1.0).contains(&progress));
-
-    let ncols = ncols.get();
-
-    if progress >= 1.0 {
-        BAR_CHR.repeat(ncols as usize).colorize(BAR_COMPLETED_COLOUR)
-    } else {
-        let block = (ncols as f32 * progress) as u16;
-        (BAR_CHR.repeat(block as usize) + BAR_END_CHR).colorize(BAR_COLOUR)
-            + &BAR_CHR
-                .repeat((ncols - block - 1) as usize)
-                .colorize(BAR_UNCOMPLETED_COLOUR)
+/// Theme (characters and styles) for [RichProgress](crate::RichProgress).
+///
+/// Styles are same as [colorize](crate::term::Colorizer::colorize) method codes (e.g. "bold red", "#00ff00").
+/// An empty style means no style is applied.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, Column, RichProgress, RichTheme};
+///
+/// let mut pb = RichProgress::new(
+///     tqdm!(total = 100),
+///     vec![Column::Animation, Column::Percentage(1)]
+/// );
+///
+/// pb.theme = RichTheme::ascii();
+/// pb.theme.percentage = "bold yellow".to_owned();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RichTheme {
+    /// Character used for drawing bar.
+    pub bar_chr: String,
+    /// Character used for drawing head of bar.
+    pub bar_end_chr: String,
+    /// Character used for drawing uncompleted part of bar.
+    pub bar_back_chr: String,
+    /// Style of completed part of bar.
+    pub bar: String,
+    /// Style of uncompleted part of bar.
+    pub bar_back: String,
+    /// Style of bar once progress is completed.
+    pub bar_finished: String,
+    /// Styles used for drawing pulsating animation.
+    pub bar_pulse: Vec<String>,
+    /// Style of count, total and file size columns.
+    pub count: String,
+    /// Style of elapsed time column.
+    pub elapsed: String,
+    /// Style of percentage column.
+    pub percentage: String,
+    /// Style of rate and transfer speed columns.
+    pub rate: String,
+    /// Style of remaining time column.
+    pub remaining: String,
+    /// Style of elapsed time displayed by [Column::RemainingOrElapsedTime](crate::Column::RemainingOrElapsedTime) once progress is completed.
+    pub remaining_finished: String,
+    /// Style of spinner column.
+    pub spinner: String,
+}
+
+impl Default for RichTheme {
+    fn default() -> Self {
+        Self {
+            bar_chr: "━".to_owned(),
+            bar_end_chr: "╸".to_owned(),
+            bar_back_chr: "━".to_owned(),
+            bar: "#F92672".to_owned(),
+            bar_back: "#525252".to_owned(),
+            bar_finished: "#729c1f".to_owned(),
+            bar_pulse: [
+                "#3a3a3a", "#3e393b", "#4c383f", "#613545", "#7b334d", "#b72c5e", "#d12a66",
+                "#e6276c", "#f42670", "#f92672", "#f42670", "#e6276c", "#d12a66", "#b72c5e",
+                "#993056", "#7b334d", "#613545", "#4c383f",
+            ]
+            .iter()
+            .map(|x| x.to_string())
+            .collect(),
+            count: "green".to_owned(),
+            elapsed: "cyan".to_owned(),
+            percentage: "magenta".to_owned(),
+            rate: "red".to_owned(),
+            remaining: "cyan".to_owned(),
+            remaining_finished: "yellow".to_owned(),
+            spinner: "green".to_owned(),
+        }
     }
 }
 
-pub(super) fn pulse(ncols: NonZeroI16, current_time: f32) -> String {
-    assert!(current_time.is_sign_positive());
+impl RichTheme {
+    /// Theme which only uses text attributes (bold and dim) instead of colours.
+    pub fn monochrome() -> Self {
+        Self {
+            bar: "bold".to_owned(),
+            bar_back: "dim".to_owned(),
+            bar_finished: "bold".to_owned(),
+            bar_pulse: ["dim", "dim", "dim", "", "bold", "bold", "bold", ""]
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+            count: "".to_owned(),
+            elapsed: "".to_owned(),
+            percentage: "bold".to_owned(),
+            rate: "".to_owned(),
+            remaining: "".to_owned(),
+            remaining_finished: "".to_owned(),
+            spinner: "bold".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Default theme which only uses ASCII characters for drawing bar.
+    pub fn ascii() -> Self {
+        Self {
+            bar_chr: "=".to_owned(),
+            bar_end_chr: ">".to_owned(),
+            bar_back_chr: "-".to_owned(),
+            ..Default::default()
+        }
+    }
 
-    let ncols = ncols.get();
-    let pulse = BAR_PULSE_COLOURS.repeat((ncols as f32 / 18_f32) as usize + 2);
+    pub(super) fn render_bar(&self, ncols: NonZeroU16, progress: f32) -> String {
+        assert!((0.0..=1.0).contains(&progress));
 
-    let pulse_len = pulse.len();
-    let offset = (-current_time * 15_f32) as i16 % 18;
-    let mut pulse_string = String::new();
+        let ncols = ncols.get();
 
-    for i in offset..(offset + ncols) {
-        if 0 > i {
-            pulse_string += &BAR_CHR.colorize(pulse[pulse_len - (-i as usize)]);
+        if progress >= 1.0 {
+            self.bar_chr
+                .repeat(ncols as usize)
+                .colorize(&self.bar_finished)
         } else {
-            pulse_string += &BAR_CHR.colorize(pulse[i as usize]);
+            let block = (ncols as f32 * progress) as u16;
+            (self.bar_chr.repeat(block as usize) + &self.bar_end_chr).colorize(&self.bar)
+                + &self
+                    .bar_back_chr
+                    .repeat((ncols - block - 1) as usize)
+                    .colorize(&self.bar_back)
         }
     }
 
-    pulse_string
+    pub(super) fn render_pulse(&self, ncols: NonZeroI16, current_time: f32) -> String {
+        assert!(current_time.is_sign_positive());
+
+        let ncols = ncols.get();
+
+        if self.bar_pulse.is_empty() {
+            return self.bar_chr.repeat(ncols as usize);
+        }
+
+        let npulse = self.bar_pulse.len() as i16;
+        let offset = (-current_time * 15_f32) as i16 % npulse;
+        let mut pulse_string = String::new();
+
+        for i in offset..(offset + ncols) {
+            pulse_string += &self
+                .bar_chr
+                .colorize(&self.bar_pulse[i.rem_euclid(npulse) as usize]);
+        }
+
+        pulse_string
+    }
 }
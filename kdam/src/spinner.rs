@@ -1,7 +1,114 @@
-use std::num::NonZeroI16;
+use std::{num::NonZeroI16, str::FromStr, time::Duration};
+
+/// Built-in spinners (name, interval in milliseconds, frames) taken from
+/// [rich repository](https://github.com/Textualize/rich/blob/master/rich/_spinners.py).
+const SPINNERS: [(&str, f32, &[&str]); 32] = [
+    (
+        "aesthetic",
+        80.0,
+        &[
+            "▰▱▱▱▱▱▱",
+            "▰▰▱▱▱▱▱",
+            "▰▰▰▱▱▱▱",
+            "▰▰▰▰▱▱▱",
+            "▰▰▰▰▰▱▱",
+            "▰▰▰▰▰▰▱",
+            "▰▰▰▰▰▰▰",
+            "▰▱▱▱▱▱▱",
+        ],
+    ),
+    ("arc", 100.0, &["◜", "◠", "◝", "◞", "◡", "◟"]),
+    ("arrow", 100.0, &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"]),
+    ("balloon", 140.0, &[" ", ".", "o", "O", "@", "*", " "]),
+    ("bounce", 120.0, &["⠁", "⠂", "⠄", "⠂"]),
+    (
+        "bouncingBall",
+        80.0,
+        &[
+            "( ●    )",
+            "(  ●   )",
+            "(   ●  )",
+            "(    ● )",
+            "(     ●)",
+            "(    ● )",
+            "(   ●  )",
+            "(  ●   )",
+            "( ●    )",
+            "(●     )",
+        ],
+    ),
+    (
+        "bouncingBar",
+        80.0,
+        &[
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]",
+            "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ],
+    ),
+    ("boxBounce", 120.0, &["▖", "▘", "▝", "▗"]),
+    ("circle", 120.0, &["◡", "⊙", "◠"]),
+    ("circleHalves", 50.0, &["◐", "◓", "◑", "◒"]),
+    ("circleQuarters", 120.0, &["◴", "◷", "◶", "◵"]),
+    (
+        "clock",
+        100.0,
+        &[
+            "🕛 ", "🕐 ", "🕑 ", "🕒 ", "🕓 ", "🕔 ", "🕕 ", "🕖 ", "🕗 ", "🕘 ", "🕙 ", "🕚 ",
+        ],
+    ),
+    (
+        "dots",
+        80.0,
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    ),
+    ("dots2", 80.0, &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"]),
+    (
+        "dots3",
+        80.0,
+        &["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
+    ),
+    ("earth", 180.0, &["🌍 ", "🌎 ", "🌏 "]),
+    (
+        "flip",
+        70.0,
+        &["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
+    ),
+    (
+        "growHorizontal",
+        120.0,
+        &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
+    ),
+    (
+        "growVertical",
+        120.0,
+        &["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+    ),
+    ("hamburger", 100.0, &["☱", "☲", "☴"]),
+    ("line", 130.0, &["-", "\\", "|", "/"]),
+    ("line2", 100.0, &["⠂", "-", "–", "—", "–", "-"]),
+    (
+        "moon",
+        80.0,
+        &["🌑 ", "🌒 ", "🌓 ", "🌔 ", "🌕 ", "🌖 ", "🌗 ", "🌘 "],
+    ),
+    ("noise", 100.0, &["▓", "▒", "░"]),
+    ("pipe", 100.0, &["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"]),
+    ("point", 125.0, &["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"]),
+    ("simpleDots", 400.0, &[".  ", ".. ", "...", "   "]),
+    (
+        "simpleDotsScrolling",
+        200.0,
+        &[".  ", ".. ", "...", " ..", "  .", "   "],
+    ),
+    ("squareCorners", 180.0, &["◰", "◳", "◲", "◱"]),
+    ("star", 70.0, &["✶", "✸", "✹", "✺", "✹", "✷"]),
+    ("toggle", 250.0, &["⊶", "⊷"]),
+    ("triangle", 50.0, &["◢", "◣", "◤", "◥"]),
+];
 
 /// Generic spinner for rendering spinner animations.
 ///
+/// Built-in spinners can be created using [from_name](Self::from_name).
 /// See more styles at [rich repository](https://github.com/Textualize/rich/blob/master/rich/_spinners.py).
 #[derive(Debug, Clone)]
 pub struct Spinner {
@@ -12,6 +119,10 @@ pub struct Spinner {
 
 impl Spinner {
     /// Create a new [Spinner](Self).
+    /// `interval` is time between two consecutive frames in milliseconds, which is divided by `speed`.
+    ///
+    /// If `interval` or `speed` is not a positive finite number, only first frame is rendered.
+    /// If `frames` is empty, spinner is rendered as an empty string.
     ///
     /// # Example
     ///
@@ -31,6 +142,13 @@ impl Spinner {
     /// println!("{}", spinner.render_frame(2.89));
     /// ```
     pub fn new(frames: &[&str], interval: f32, speed: f32) -> Self {
+        let (interval, speed) =
+            if interval.is_finite() && interval > 0.0 && speed.is_finite() && speed > 0.0 {
+                (interval, speed)
+            } else {
+                (f32::INFINITY, 1.0)
+            };
+
         Self {
             frames: frames
                 .iter()
@@ -41,8 +159,47 @@ impl Spinner {
         }
     }
 
+    /// Create a new [Spinner](Self) from built-in spinners with their default interval.
+    /// Name is matched case insensitively.
+    ///
+    /// Returns `None`, if there is no spinner with given name.
+    /// See [names](Self::names) for all available spinners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Spinner;
+    ///
+    /// let spinner = Spinner::from_name("dots").unwrap();
+    /// assert_eq!(spinner.render_frame(0.0), "⠋");
+    /// assert!(Spinner::from_name("bouncingbar").is_some());
+    /// assert!(Spinner::from_name("unknown").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        SPINNERS
+            .iter()
+            .find(|(x, _, _)| x.eq_ignore_ascii_case(name))
+            .map(|(_, interval, frames)| Self::new(frames, *interval, 1.0))
+    }
+
+    /// Returns names of all built-in spinners.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        SPINNERS.iter().map(|(name, _, _)| *name)
+    }
+
+    /// Returns time between two consecutive frames.
+    ///
+    /// Returns [Duration::MAX], if spinner only renders first frame.
+    pub fn frame_interval(&self) -> Duration {
+        Duration::try_from_secs_f32(self.interval / self.speed / 1000.0).unwrap_or(Duration::MAX)
+    }
+
     /// Render a single frame.
     pub fn render_frame(&self, elapsed_time: f32) -> String {
+        if self.frames.is_empty() {
+            return String::new();
+        }
+
         let frame_no = (elapsed_time * self.speed) / (self.interval / 1000.0);
         self.frames
             .get(frame_no as usize % self.frames.len())
@@ -52,6 +209,10 @@ impl Spinner {
 
     /// Render multiple frames upto `ncols` with an pulsating animation.
     pub fn render_frames(&self, elapsed_time: f32, ncols: NonZeroI16) -> String {
+        if self.frames.is_empty() {
+            return String::new();
+        }
+
         let ncols = ncols.get();
         let pulse = self
             .frames
@@ -74,3 +235,20 @@ impl Spinner {
         pulse_string
     }
 }
+
+impl FromStr for Spinner {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| format!("unknown spinner: {}", s))
+    }
+}
+
+impl TryFrom<&str> for Spinner {
+    type Error = String;
+
+    /// Create a built-in spinner, see [from_name](Self::from_name).
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
@@ -1,15 +1,24 @@
 use super::{
-    styles::{Animation, Colour},
+    callbacks::Callbacks,
+    checkpoint::Checkpoint,
+    derive,
+    history::RateHistory,
+    styles::{self, Animation, Colour, Marquee, Segment},
+    ticker::SteadyTick,
+    truncate::{self, Truncate},
     BarExt,
 };
 use crate::{
     format,
-    term::{self, Colorizer, InitializedOutput, Writer},
+    term::{self, Colorizer, ColourSupport, InitializedOutput, TaskbarState, Writer},
 };
 use std::{
-    io::{stdin, Result, Write},
+    collections::VecDeque,
+    io::{ErrorKind, Result, Write},
     num::NonZeroU16,
-    time::Instant,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 #[cfg(feature = "notebook")]
@@ -27,6 +36,8 @@ use crate::spinner::Spinner;
 #[cfg(feature = "template")]
 use formatx::Template;
 
+/// Minimum number of columns reserved for bar animation, if [ncols](Bar::ncols) is not set.
+const MIN_ANIMATION_NCOLS: u16 = 10;
 
 /// Core implemention of console progress bar.
 ///
@@ -49,6 +60,7 @@ pub struct Bar {
     pub animation: Animation,
     #[cfg(feature = "template")]
     pub bar_format: Option<Template>,
+    pub checkpoint: Option<(PathBuf, f32)>,
     pub colour: Option<Colour>,
     pub desc: String,
     pub delay: f32,
@@ -56,30 +68,89 @@ pub struct Bar {
     pub dynamic_miniters: bool,
     pub dynamic_ncols: bool,
     pub force_refresh: bool,
+    pub indefinite_animation: Option<Animation>,
     pub inverse_unit: bool,
     pub leave: bool,
+    pub markup: bool,
+    pub marquee: Option<Marquee>,
     pub mininterval: f32,
     pub miniters: usize,
     pub ncols: Option<u16>,
     pub position: u16,
     pub postfix: String,
+    pub segments: Vec<Segment>,
     pub total: usize,
     #[cfg(feature = "spinner")]
     pub spinner: Option<Spinner>,
+    #[cfg(feature = "template")]
+    pub summary_format: Option<Template>,
+    pub taskbar: Option<TaskbarState>,
+    #[cfg(feature = "template")]
+    pub title_format: Option<Template>,
+    pub truncate: Vec<Truncate>,
     pub unit: String,
     pub unit_divisor: usize,
     pub unit_scale: bool,
     pub writer: InitializedOutput,
     // Non Builder Fields
     pub bar_length: u16,
+    pub(super) callbacks: Callbacks,
     #[cfg(feature = "notebook")]
     container: Option<notebook::PyContainer>,
     pub counter: usize,
     current_ncols: u16,
     elapsed_time: f32,
+    last_checkpoint: f32,
+    rate_history: RateHistory,
+    terminal: TerminalState,
     timer: Instant,
 }
 
+/// Taskbar progress and window title escape codes emitted by a progress bar,
+/// which are restored when progress bar is cleared or dropped.
+#[derive(Debug, Default)]
+struct TerminalState {
+    taskbar_shown: bool,
+    title_pushed: bool,
+    writer: Option<InitializedOutput>,
+}
+
+impl TerminalState {
+    /// Returns escape codes for removing taskbar progress and restoring window title (if `restore_title` is `true`).
+    fn restore(&mut self, restore_title: bool) -> String {
+        let mut escape_codes = String::new();
+
+        if self.taskbar_shown {
+            self.taskbar_shown = false;
+            escape_codes += &TaskbarState::Hidden.escape_code(1.0);
+        }
+
+        if restore_title && self.title_pushed {
+            self.title_pushed = false;
+            escape_codes += "\x1b[23;2t";
+        }
+
+        escape_codes
+    }
+}
+
+impl Clone for TerminalState {
+    /// Clones never restore taskbar progress or window title of original progress bar.
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Drop for TerminalState {
+    fn drop(&mut self) {
+        let escape_codes = self.restore(true);
+
+        if let (false, Some(writer)) = (escape_codes.is_empty(), &mut self.writer) {
+            let _ = writer.print(escape_codes.as_bytes());
+        }
+    }
+}
+
 impl Default for Bar {
     fn default() -> Self {
         let mut ncols = None;
@@ -92,6 +163,7 @@ impl Default for Bar {
             animation: Animation::Tqdm,
             #[cfg(feature = "template")]
             bar_format: None,
+            checkpoint: None,
             colour: None,
             delay: 0.0,
             desc: "".to_owned(),
@@ -99,26 +171,40 @@ impl Default for Bar {
             dynamic_miniters: false,
             dynamic_ncols: false,
             force_refresh: false,
+            indefinite_animation: Some(Animation::Bounce),
             inverse_unit: false,
             leave: true,
+            markup: false,
+            marquee: None,
             mininterval: 0.1,
             miniters: 1,
             ncols,
             total: 0,
             position: 0,
             postfix: "".to_string(),
+            segments: vec![],
             #[cfg(feature = "spinner")]
             spinner: None,
+            #[cfg(feature = "template")]
+            summary_format: None,
+            taskbar: None,
+            #[cfg(feature = "template")]
+            title_format: None,
+            truncate: Truncate::defaults(),
             unit: "it".to_owned(),
             unit_divisor: 1000,
             unit_scale: false,
             writer: InitializedOutput::Stderr,
             bar_length: 0,
+            callbacks: Callbacks::default(),
             counter: 0,
             current_ncols: 0,
             elapsed_time: 0.0,
             #[cfg(feature = "notebook")]
             container: None,
+            last_checkpoint: 0.0,
+            rate_history: RateHistory::default(),
+            terminal: TerminalState::default(),
             timer: Instant::now(),
         }
     }
@@ -169,25 +255,32 @@ impl Bar {
         &mut self,
         bar_format: T,
     ) -> ::std::result::Result<(), String> {
-        let bar_format = bar_format
-            .into()
-            .parse::<Template>()
-            .map_err(|x| x.message())?;
-        let mut bar_format_check = bar_format.clone();
-        bar_format_check.replace("desc", "");
-        bar_format_check.replace("percentage", 0.0);
-        bar_format_check.replace("count", 0);
-        bar_format_check.replace("total", 0);
-        bar_format_check.replace("elapsed", 0);
-        bar_format_check.replace("remaining", 0);
-        bar_format_check.replace("rate", 0.0);
-        bar_format_check.replace("unit", "");
-        bar_format_check.replace("postfix", "");
-        #[cfg(feature = "spinner")]
-        bar_format_check.replace("spinner", "");
-        bar_format_check.replace("animation", "");
-        bar_format_check.text().map_err(|x| x.message())?;
-        self.bar_format = Some(bar_format);
+        self.bar_format = Some(parse_template(bar_format.into())?);
+        Ok(())
+    }
+
+    /// Set/Modify [title_format](Self::title_format) property.
+    #[cfg(feature = "template")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
+    pub fn set_title_format<T: Into<String>>(
+        &mut self,
+        title_format: T,
+    ) -> ::std::result::Result<(), String> {
+        self.title_format = Some(parse_template(title_format.into())?);
+        Ok(())
+    }
+
+    /// Set/Modify [summary_format](Self::summary_format) property.
+    #[cfg(feature = "template")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
+    pub fn set_summary_format<T: Into<String>>(
+        &mut self,
+        summary_format: T,
+    ) -> ::std::result::Result<(), String> {
+        self.summary_format = Some(parse_template_with(
+            summary_format.into(),
+            SUMMARY_PLACEHOLDERS,
+        )?);
         Ok(())
     }
 
@@ -201,10 +294,153 @@ impl Bar {
         self.postfix = ", ".to_owned() + &postfix.into();
     }
 
+    /// Set a callback which is called before first update or refresh of progress bar.
+    /// It is called again after [reset](crate::BarExt::reset).
+    ///
+    /// Callbacks are called while progress bar is borrowed, so they must not access progress bar itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 10);
+    /// pb.on_start(|| eprintln!("started"));
+    /// pb.on_update(|counter, delta| assert_eq!(counter % delta, 0));
+    /// pb.on_complete(|| eprintln!("\ncompleted"));
+    ///
+    /// for _ in 0..10 {
+    ///     pb.update(1).unwrap();
+    /// }
+    /// ```
+    pub fn on_start<F: Fn() + Send + Sync + 'static>(&mut self, callback: F) {
+        self.callbacks.on_start = Some(Arc::new(callback));
+    }
+
+    /// Set a callback which is called with `counter` and `delta` after counter is increased.
+    pub fn on_update<F: Fn(usize, usize) + Send + Sync + 'static>(&mut self, callback: F) {
+        self.callbacks.on_update = Some(Arc::new(callback));
+    }
+
+    /// Set a callback which is called with rendered text after progress bar is displayed.
+    ///
+    /// It is not called when progress bar is redrawn by [SteadyTick](crate::SteadyTick).
+    pub fn on_refresh<F: Fn(&str) + Send + Sync + 'static>(&mut self, callback: F) {
+        self.callbacks.on_refresh = Some(Arc::new(callback));
+    }
+
+    /// Set a callback which is called once when counter reaches total, before final refresh of progress bar.
+    ///
+    /// It is never called if `total` is `0`.
+    pub fn on_complete<F: Fn() + Send + Sync + 'static>(&mut self, callback: F) {
+        self.callbacks.on_complete = Some(Arc::new(callback));
+    }
+
+    /// Set a callback which is called after progress bar is [reset](crate::BarExt::reset).
+    pub fn on_reset<F: Fn() + Send + Sync + 'static>(&mut self, callback: F) {
+        self.callbacks.on_reset = Some(Arc::new(callback));
+    }
+
     // -----------------------------------------------------------------------------------------
     // Methods
     // -----------------------------------------------------------------------------------------
 
+    /// Update counter of a [segment](Self::segments) and progress bar by `n`.
+    /// Segment is created (without any colour) if it doesn't exist.
+    ///
+    /// Returns whether an update was triggered or not depending on constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Segment};
+    ///
+    /// let mut pb = tqdm!(
+    ///     total = 100,
+    ///     animation = "segmented",
+    ///     segments = vec![
+    ///         Segment::new("passed", "green"),
+    ///         Segment::new("failed", "red"),
+    ///         Segment::new("skipped", "yellow"),
+    ///     ],
+    ///     bar_format = "{desc}|{animation}| {segment name=passed} passed, {segment name=failed} failed"
+    /// );
+    ///
+    /// for i in 0..100 {
+    ///     pb.update_segment(if i % 10 == 0 { "failed" } else { "passed" }, 1).unwrap();
+    /// }
+    ///
+    /// eprintln!();
+    /// ```
+    pub fn update_segment(&mut self, name: &str, n: usize) -> Result<bool> {
+        match self.segments.iter_mut().find(|x| x.name == name) {
+            Some(segment) => segment.count += n,
+            None => {
+                let mut segment = Segment::new(name, "");
+                segment.count = n;
+                self.segments.push(segment);
+            }
+        }
+
+        self.update(n)
+    }
+
+    /// Save progress bar state (counter, total, elapsed time, description, postfix, segment counts and rate history)
+    /// to a file, which can be restored using [resume_from](crate::BarBuilder::resume_from) builder option.
+    /// See [checkpoint](crate::BarBuilder::checkpoint) builder option for saving state periodically.
+    ///
+    /// File is replaced atomically, so this method can be called periodically without risk of corrupting
+    /// last checkpoint on crash.
+    ///
+    /// Returns `Err`, if writing to file fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 1000, resume_from = "job.checkpoint");
+    ///
+    /// for i in pb.counter..1000 {
+    ///     // ...
+    ///     pb.update(1).unwrap();
+    ///
+    ///     if i % 100 == 0 {
+    ///         pb.checkpoint_to("job.checkpoint").unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn checkpoint_to<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        Checkpoint {
+            counter: self.counter,
+            total: self.total,
+            elapsed_time: self.elapsed_time(),
+            desc: self.desc.clone(),
+            postfix: self.postfix.clone(),
+            rate_history: self.rate_history.clone(),
+            segments: self
+                .segments
+                .iter()
+                .map(|x| (x.name.clone(), x.count))
+                .collect(),
+        }
+        .write(path.as_ref())?;
+        self.last_checkpoint = self.elapsed_time;
+        Ok(())
+    }
+
+    /// Save [checkpoint](Self::checkpoint) if its interval has passed since last saved checkpoint.
+    pub(super) fn autosave_checkpoint(&mut self) -> Result<()> {
+        if let Some((path, interval)) = &self.checkpoint
+            && self.elapsed_time - self.last_checkpoint >= *interval
+        {
+            let path = path.clone();
+            self.checkpoint_to(path)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns whether progress is completed or not.
     ///
     /// If `total` is `0`, it always returns `false`.
@@ -219,6 +455,43 @@ impl Bar {
     /// Set and returns progress elapsed time.
     pub fn elapsed_time(&mut self) -> f32 {
         self.elapsed_time = self.timer.elapsed().as_secs_f32();
+        self.rate_history.record(self.counter, self.elapsed_time);
+        self.elapsed_time
+    }
+
+    /// Start redrawing shared progress bar every `interval` from background ticker thread,
+    /// so that spinner, animation and elapsed time keep moving even if no updates arrive.
+    ///
+    /// Ticking is stopped once progress is completed, progress bar is dropped or returned handle is dropped.
+    /// See [SteadyTick](crate::SteadyTick) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Bar, BarExt};
+    /// use std::{
+    ///     sync::{Arc, Mutex},
+    ///     time::Duration,
+    /// };
+    ///
+    /// let pb = Arc::new(Mutex::new(tqdm!(total = 10)));
+    /// let steady_tick = Bar::enable_steady_tick(&pb, Duration::from_millis(80));
+    ///
+    /// for _ in 0..10 {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    ///     pb.lock().unwrap().update(1).unwrap();
+    /// }
+    ///
+    /// drop(steady_tick);
+    /// eprintln!();
+    /// ```
+    pub fn enable_steady_tick(pb: &Arc<Mutex<Self>>, interval: Duration) -> SteadyTick {
+        SteadyTick::new(pb, interval)
+    }
+
+    /// Returns progress elapsed time set by last call of [elapsed_time](Self::elapsed_time) method.
+    #[cfg(feature = "rich")]
+    pub(crate) fn last_elapsed_time(&self) -> f32 {
         self.elapsed_time
     }
 
@@ -231,6 +504,25 @@ impl Bar {
         }
     }
 
+    /// Returns description rendered from rich markup (if [markup](Self::markup) is `true`),
+    /// scrolled using [marquee](Self::marquee) if it is set.
+    pub fn fmt_desc(&self) -> String {
+        if let Some(marquee) = &self.marquee {
+            marquee.render(&self.fmt_markup(&self.desc), self.elapsed_time)
+        } else {
+            self.fmt_markup(&self.desc)
+        }
+    }
+
+    /// Returns text rendered from rich markup, if [markup](Self::markup) is `true` else text is returned as it is.
+    pub fn fmt_markup(&self, text: &str) -> String {
+        if self.markup {
+            text.markup()
+        } else {
+            text.to_owned()
+        }
+    }
+
     /// Returns formatted elapsed time.
     pub fn fmt_elapsed_time(&self) -> String {
         format::interval(self.elapsed_time as usize, false)
@@ -251,29 +543,32 @@ impl Bar {
         if !self.started() {
             format!("?{}/s", self.unit)
         } else {
-            let rate = self.rate();
+            self.fmt_rate_of(self.rate())
+        }
+    }
 
-            if rate < 1. && self.inverse_unit {
-                format!(
-                    "{}/{}",
-                    if self.unit_scale {
-                        format::time(1. / (rate as f64))
-                    } else {
-                        format!("{:.2}s", 1. / rate)
-                    },
-                    self.unit
-                )
-            } else {
-                format!(
-                    "{}{}/s",
-                    if self.unit_scale {
-                        format::size_of(rate as f64, self.unit_divisor as f64)
-                    } else {
-                        format!("{:.2}", rate)
-                    },
-                    self.unit
-                )
-            }
+    /// Format given update rate like [fmt_rate](Self::fmt_rate).
+    fn fmt_rate_of(&self, rate: f32) -> String {
+        if rate < 1. && self.inverse_unit {
+            format!(
+                "{}/{}",
+                if self.unit_scale {
+                    format::time(1. / (rate as f64))
+                } else {
+                    format!("{:.2}s", 1. / rate)
+                },
+                self.unit
+            )
+        } else {
+            format!(
+                "{}{}/s",
+                if self.unit_scale {
+                    format::size_of(rate as f64, self.unit_divisor as f64)
+                } else {
+                    format!("{:.2}", rate)
+                },
+                self.unit
+            )
         }
     }
 
@@ -286,6 +581,132 @@ impl Bar {
         }
     }
 
+    /// Returns `OSC 9;4` escape code for reporting progress in terminal tab/taskbar.
+    ///
+    /// If [taskbar](Self::taskbar) is `None` or colorization is disabled, it always returns an empty string.
+    pub fn fmt_taskbar(&self) -> String {
+        if term::colour_support() == ColourSupport::None {
+            return String::new();
+        }
+
+        match self.taskbar {
+            Some(_) if self.completed() => TaskbarState::Hidden.escape_code(1.0),
+            Some(TaskbarState::Normal) if self.indefinite() => {
+                TaskbarState::Indeterminate.escape_code(0.0)
+            }
+            Some(state) => state.escape_code(self.percentage()),
+            None => String::new(),
+        }
+    }
+
+    /// Returns `OSC 2` escape code for reporting progress in terminal window title.
+    ///
+    /// If [title_format](Self::title_format) is `None`, progress bar is completed or colorization is disabled,
+    /// it always returns an empty string.
+    pub fn fmt_title(&self) -> String {
+        #[cfg(feature = "template")]
+        if let Some(title_format) = &self.title_format
+            && !self.completed()
+            && term::colour_support() != ColourSupport::None
+        {
+            let mut title_format = title_format.clone();
+            let desc = self.fmt_markup(&self.desc);
+            self.fill_template(&mut title_format, &desc, &self.postfix);
+            title_format.replace("animation", "");
+            return format!(
+                "\x1b]2;{}\x1b\\",
+                title_format.text().unwrap_or_default().trim_ansi()
+            );
+        }
+
+        String::new()
+    }
+
+    /// Returns taskbar progress and window title escape codes to be printed with progress bar.
+    ///
+    /// Previous window title is saved before it is first updated and restored upon completion or when progress bar is dropped.
+    pub(super) fn terminal_escape_codes(&mut self) -> String {
+        let mut escape_codes = self.fmt_taskbar();
+        self.terminal.taskbar_shown = !escape_codes.is_empty() && !self.completed();
+
+        if self.completed() {
+            if self.terminal.title_pushed {
+                self.terminal.title_pushed = false;
+                escape_codes += "\x1b[23;2t";
+            }
+        } else {
+            let title = self.fmt_title();
+
+            if !title.is_empty() {
+                if !self.terminal.title_pushed {
+                    self.terminal.title_pushed = true;
+                    escape_codes += "\x1b[22;2t";
+                }
+
+                escape_codes += &title;
+            }
+        }
+
+        if !escape_codes.is_empty() && self.terminal.writer.is_none() {
+            self.terminal.writer = Some(self.writer.clone());
+        }
+
+        escape_codes
+    }
+
+    /// Returns sparkline of recent update rates (see [rate_history](Self::rate_history)) with `width` columns,
+    /// like `▁▂▃▅▇▇▅`.
+    pub fn fmt_sparkline(&self, width: usize) -> String {
+        styles::render_sparkline(self.rate_history.samples.iter(), width)
+    }
+
+    /// Returns formatted completion summary, like `download: 1.2 GB in 00:42 (avg 29 MB/s, peak 41 MB/s)`.
+    ///
+    /// If [summary_format](Self::summary_format) is `None`, it always returns `None`.
+    pub fn fmt_summary(&self) -> Option<String> {
+        #[cfg(feature = "template")]
+        if let Some(summary_format) = &self.summary_format {
+            let mut summary_format = summary_format.clone();
+            let desc = self.fmt_markup(&self.desc);
+            self.fill_template(&mut summary_format, &desc, &self.postfix);
+            summary_format.replace("animation", "");
+
+            summary_format.replace_from_callback("peak_rate", |placeholder| {
+                if self.unit_scale {
+                    placeholder.format_spec.format(format::size_of(
+                        self.peak_rate() as f64,
+                        self.unit_divisor as f64,
+                    ))
+                } else {
+                    placeholder.format_spec.format(self.peak_rate())
+                }
+            });
+
+            summary_format.replace_from_callback("file_size", |placeholder| {
+                placeholder.format_spec.format(format::file_size(
+                    self.counter as f64,
+                    self.unit_divisor == 1024,
+                ))
+            });
+
+            summary_format.replace_from_callback("speed", |placeholder| {
+                placeholder
+                    .format_spec
+                    .format(self.fmt_rate_of(self.rate()))
+            });
+
+            summary_format.replace_from_callback("peak_speed", |placeholder| {
+                placeholder
+                    .format_spec
+                    .format(self.fmt_rate_of(self.peak_rate()))
+            });
+
+            return summary_format.text().ok();
+        }
+
+        None
+    }
+
     /// Returns formatted total value.
     pub fn fmt_total(&self) -> String {
         if self.unit_scale {
@@ -300,15 +721,22 @@ impl Bar {
         self.total == 0
     }
 
+    /// Returns number of columns reserved for bar animation, while truncating description and postfix.
+    fn reserved_ncols(&self) -> usize {
+        self.ncols.unwrap_or(MIN_ANIMATION_NCOLS) as usize
+    }
+
     /// Set and returns number of columns for bar animation with given padding.
     pub fn ncols_for_animation(&mut self, padding: u16) -> u16 {
         if self.dynamic_ncols || ((padding + self.current_ncols) != self.bar_length) {
             if let Some(ncols) = self.ncols {
-                self.current_ncols = ncols;
+                // Fixed width animation is shrinked, if it alone overflows terminal width.
+                self.current_ncols = match term::width() {
+                    Some(width) => ncols.min(width.saturating_sub(padding)),
+                    None => ncols,
+                };
             } else if let Some(width) = term::width() {
-                if width >= padding {
-                    self.current_ncols = width - padding;
-                }
+                self.current_ncols = width.saturating_sub(padding);
             } else {
                 self.current_ncols = 10;
             }
@@ -337,6 +765,17 @@ impl Bar {
         self.counter as f32 / self.elapsed_time
     }
 
+    /// Returns highest update rate (iterations per second) seen so far.
+    ///
+    /// Before calling this method, [elapsed_time](crate::Bar::elapsed_time) method should be called.
+    pub fn peak_rate(&self) -> f32 {
+        if self.elapsed_time > 0.0 {
+            self.rate_history.peak.max(self.rate())
+        } else {
+            self.rate_history.peak
+        }
+    }
+
     /// Returns remaining time (ETA) for progress completion.
     ///
     /// If `total` is `0`, it always returns infinity.
@@ -367,7 +806,7 @@ impl Bar {
             let miniters_constraint = if self.miniters <= 1 {
                 true
             } else {
-                self.counter % self.miniters == 0
+                self.counter.is_multiple_of(self.miniters)
             };
 
             if (mininterval_constraint && miniters_constraint && delay_constraint)
@@ -384,66 +823,222 @@ impl Bar {
         false
     }
 
+    /// Returns recent update rate samples (oldest first), which are taken at most once per second.
+    pub fn rate_history(&self) -> &VecDeque<f32> {
+        &self.rate_history.samples
+    }
+
     /// Returns whether progress is started i.e. `counter` is `0` or not.
     pub fn started(&self) -> bool {
         self.counter > 0
     }
-}
 
-impl BarExt for Bar {
-    fn clear(&mut self) -> Result<()> {
-        self.writer.print_at(
-            self.position,
-            " ".repeat(term::width().unwrap_or(self.bar_length) as usize)
-                .as_bytes(),
-        )
+    /// Truncate texts in order of [truncate](Self::truncate) priority,
+    /// so that a display of `length` width fits within terminal width.
+    ///
+    /// Returns whether any text was truncated or not.
+    pub fn truncate_to_fit(&self, length: usize, texts: &mut [(Truncate, String)]) -> bool {
+        truncate::fit(&self.truncate, length, texts)
     }
 
-    fn input<T: Into<String>>(&mut self, text: T) -> Result<String> {
-        self.clear()?;
-        self.writer.print(text.into().as_bytes())?;
+    /// Returns whether animation is drawn using segments or not.
+    fn segmented(&self) -> bool {
+        matches!(self.animation, Animation::Segmented) && !self.segments.is_empty()
+    }
 
-        let mut buf = String::new();
-        stdin().read_line(&mut buf)?;
+    /// Truncate description (with `": "` suffix) and postfix to fit them
+    /// along with remaining display of `length` width within terminal width.
+    fn fit_desc_postfix(&self, length: usize) -> (String, String) {
+        let desc = self.fmt_desc();
+        let desc_length = if desc.is_empty() {
+            0
+        } else {
+            desc.len_ansi() + 2
+        };
+        let mut texts = [
+            (Truncate::Desc, desc),
+            (Truncate::Postfix, self.postfix.clone()),
+        ];
 
-        if self.leave {
-            self.refresh()?;
-        }
+        self.truncate_to_fit(length + desc_length + self.postfix.len_ansi(), &mut texts);
 
-        Ok(buf)
-    }
+        let [(_, desc), (_, postfix)] = texts;
 
-    fn refresh(&mut self) -> Result<()> {
-        self.elapsed_time();
+        if desc.is_empty() {
+            (desc, postfix)
+        } else {
+            (desc + ": ", postfix)
+        }
+    }
 
-        #[cfg(feature = "notebook")]
-        if notebook::running() {
-            if self.completed() {
-                self.total = self.counter;
+    #[cfg(feature = "template")]
+    fn fill_template(&self, template: &mut Template, desc: &str, postfix: &str) {
+        template.replace_with_callback("desc", desc, |fmtval, placeholder| {
+            if desc.is_empty() {
+                fmtval
+            } else {
+                fmtval
+                    + &placeholder
+                        .attr("suffix")
+                        .unwrap_or_else(|| ": ".to_owned())
+            }
+        });
+
+        template.replace_from_callback("percentage", |placeholder| {
+            placeholder.format_spec.format(self.percentage() * 100.)
+        });
+
+        template.replace_from_callback("count", |placeholder| {
+            if self.unit_scale {
+                placeholder.format_spec.format(format::size_of(
+                    self.counter as f64,
+                    self.unit_divisor as f64,
+                ))
+            } else {
+                placeholder.format_spec.format(self.counter)
             }
+        });
 
-            let _ = self.render();
-            return Ok(());
-        }
+        template.replace_from_callback("total", |placeholder| {
+            if self.unit_scale {
+                placeholder
+                    .format_spec
+                    .format(format::size_of(self.total as f64, self.unit_divisor as f64))
+            } else {
+                placeholder.format_spec.format(self.total)
+            }
+        });
+
+        template.replace_from_callback("elapsed", |placeholder| {
+            let human = placeholder
+                .attr("human")
+                .and_then(|x| x.parse::<bool>().ok())
+                .unwrap_or(false);
+            placeholder
+                .format_spec
+                .format(format::interval(self.elapsed_time as usize, human))
+        });
+
+        template.replace_from_callback("remaining", |placeholder| {
+            if self.indefinite() {
+                placeholder.format_spec.format("inf")
+            } else {
+                let human = placeholder
+                    .attr("human")
+                    .and_then(|x| x.parse::<bool>().ok())
+                    .unwrap_or(false);
+                placeholder
+                    .format_spec
+                    .format(format::interval(self.remaining_time() as usize, human))
+            }
+        });
+
+        // inverse_unit field is not considered here.
+        template.replace_from_callback("rate", |placeholder| {
+            if self.unit_scale {
+                placeholder.format_spec.format(format::size_of(
+                    self.rate() as f64,
+                    self.unit_divisor as f64,
+                ))
+            } else {
+                placeholder.format_spec.format(self.rate())
+            }
+        });
+
+        template.replace("unit", &self.unit);
+        template.replace("postfix", postfix);
+
+        template.replace_from_callback("segment", |placeholder| {
+            let count = placeholder
+                .attr("name")
+                .and_then(|name| self.segments.iter().find(|x| x.name == name))
+                .map(|x| x.count)
+                .unwrap_or(0);
+            placeholder.format_spec.format(count)
+        });
+
+        template.replace_from_callback("sparkline", |placeholder| {
+            let width = placeholder
+                .attr("width")
+                .and_then(|x| x.parse::<usize>().ok())
+                .unwrap_or(10);
+            placeholder.format_spec.format(self.fmt_sparkline(width))
+        });
 
-        if self.completed() {
-            if !self.leave && self.position > 0 {
-                return self.clear();
+        #[cfg(feature = "spinner")]
+        template.replace_from_callback("spinner", |_| {
+            if let Some(spinner) = &self.spinner {
+                spinner.render_frame(self.elapsed_time)
+            } else {
+                "".to_owned()
             }
+        });
+    }
+}
 
-            self.total = self.counter;
-        }
+/// Placeholders of [summary_format](Bar::summary_format) in addition to [bar_format](Bar::bar_format) placeholders.
+#[cfg(feature = "template")]
+const SUMMARY_PLACEHOLDERS: &[&str] = &["peak_rate", "file_size", "speed", "peak_speed"];
 
-        let text = self.render();
-        let bar_length = text.len_ansi() as u16;
+#[cfg(feature = "template")]
+fn parse_template(template: String) -> ::std::result::Result<Template, String> {
+    parse_template_with(template, &[])
+}
 
-        if bar_length > self.bar_length {
-            self.clear()?;
-            self.bar_length = bar_length;
-        }
+#[cfg(feature = "template")]
+fn parse_template_with(
+    template: String,
+    placeholders: &[&str],
+) -> ::std::result::Result<Template, String> {
+    let template = template.parse::<Template>().map_err(|x| x.message())?;
+    let mut template_check = template.clone();
+    template_check.replace("desc", "");
+    template_check.replace("percentage", 0.0);
+    template_check.replace("count", 0);
+    template_check.replace("total", 0);
+    template_check.replace("elapsed", 0);
+    template_check.replace("remaining", 0);
+    template_check.replace("rate", 0.0);
+    template_check.replace("unit", "");
+    template_check.replace("postfix", "");
+    template_check.replace("segment", 0);
+    template_check.replace("sparkline", "");
+    #[cfg(feature = "spinner")]
+    template_check.replace("spinner", "");
+    template_check.replace("animation", "");
 
-        self.writer.print_at(self.position, text.as_bytes())?;
-        Ok(())
+    for placeholder in placeholders {
+        template_check.replace(placeholder, 0.0);
+    }
+
+    template_check.text().map_err(|x| x.message())?;
+    Ok(template)
+}
+
+/// Returns display width of filled template, excluding width of `{animation}` placeholder.
+#[cfg(feature = "template")]
+fn text_length(template: &Template) -> usize {
+    let mut template = template.clone();
+    template.replace("animation", "");
+    template.unchecked_text().len_ansi()
+}
+
+impl BarExt for Bar {
+    fn clear(&mut self) -> Result<()> {
+        let escape_codes = self.terminal.restore(false);
+        self.writer.print_at(
+            self.position,
+            (escape_codes + &" ".repeat(term::width().unwrap_or(self.bar_length) as usize))
+                .as_bytes(),
+        )
+    }
+
+    fn input<T: Into<String>>(&mut self, text: T) -> Result<String> {
+        derive::input(self, text)
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        derive::refresh(self)
     }
 
     fn render(&mut self) -> String {
@@ -473,91 +1068,11 @@ impl BarExt for Bar {
 
         #[cfg(feature = "template")]
         if let Some(bar_format) = &self.bar_format {
-            let mut bar_format = bar_format.clone();
-
-            bar_format.replace_with_callback("desc", &self.desc, |fmtval, placeholder| {
-                if self.desc.is_empty() {
-                    fmtval
-                } else {
-                    fmtval
-                        + &placeholder
-                            .attr("suffix")
-                            .unwrap_or_else(|| ": ".to_owned())
-                }
-            });
-
-            bar_format.replace_from_callback("percentage", |placeholder| {
-                placeholder.format_spec.format(self.percentage() * 100.)
-            });
-
-            bar_format.replace_from_callback("count", |placeholder| {
-                if self.unit_scale {
-                    placeholder.format_spec.format(format::size_of(
-                        self.counter as f64,
-                        self.unit_divisor as f64,
-                    ))
-                } else {
-                    placeholder.format_spec.format(self.counter)
-                }
-            });
-
-            bar_format.replace_from_callback("total", |placeholder| {
-                if self.unit_scale {
-                    placeholder
-                        .format_spec
-                        .format(format::size_of(self.total as f64, self.unit_divisor as f64))
-                } else {
-                    placeholder.format_spec.format(self.total)
-                }
-            });
-
-            bar_format.replace_from_callback("elapsed", |placeholder| {
-                let human = placeholder
-                    .attr("human")
-                    .and_then(|x| x.parse::<bool>().ok())
-                    .unwrap_or(false);
-                placeholder
-                    .format_spec
-                    .format(format::interval(self.elapsed_time as usize, human))
-            });
-
-            bar_format.replace_from_callback("remaining", |placeholder| {
-                if self.indefinite() {
-                    placeholder.format_spec.format("inf")
-                } else {
-                    let human = placeholder
-                        .attr("human")
-                        .and_then(|x| x.parse::<bool>().ok())
-                        .unwrap_or(false);
-                    placeholder
-                        .format_spec
-                        .format(format::interval(self.remaining_time() as usize, human))
-                }
-            });
-
-            // inverse_unit field is not considered here.
-            bar_format.replace_from_callback("rate", |placeholder| {
-                if self.unit_scale {
-                    placeholder.format_spec.format(format::size_of(
-                        self.rate() as f64,
-                        self.unit_divisor as f64,
-                    ))
-                } else {
-                    placeholder.format_spec.format(self.rate())
-                }
-            });
-
-            bar_format.replace("unit", &self.unit);
-            bar_format.replace("postfix", &self.postfix);
+            let template = bar_format.clone();
+            let mut bar_format = template.clone();
+            let desc = self.fmt_desc();
 
-            #[cfg(feature = "spinner")]
-            bar_format.replace_from_callback("spinner", |_| {
-                if let Some(spinner) = &self.spinner {
-                    spinner.render_frame(self.elapsed_time)
-                } else {
-                    "".to_owned()
-                }
-            });
+            self.fill_template(&mut bar_format, &desc, &self.postfix);
 
             #[cfg(feature = "notebook")]
             if let Some(container) = &self.container {
@@ -583,16 +1098,43 @@ impl BarExt for Bar {
                 return "".to_owned();
             }
 
-            let length = bar_format.unchecked_text().len_ansi() as u16;
+            let has_animation = bar_format.contains("animation");
+            let mut length = text_length(&bar_format);
+            let mut texts = [
+                (Truncate::Desc, desc),
+                (Truncate::Postfix, self.postfix.clone()),
+            ];
+            let animation_length = if has_animation {
+                self.reserved_ncols()
+            } else {
+                0
+            };
+
+            if self.truncate_to_fit(length + animation_length, &mut texts) {
+                bar_format = template;
+                self.fill_template(&mut bar_format, &texts[0].1, &texts[1].1);
+                length = text_length(&bar_format);
+            }
 
-            if bar_format.contains("animation") && length > 11 {
-                let ncols = self.ncols_for_animation(length - 11);
+            if has_animation {
+                let ncols = self.ncols_for_animation(length as u16);
 
                 if ncols > 0 {
                     bar_format.replace_from_callback("animation", |_| {
-                        let render = self
-                            .animation
-                            .render(NonZeroU16::new(ncols).unwrap(), self.percentage());
+                        let ncols = NonZeroU16::new(ncols).unwrap();
+                        let render = match &self.indefinite_animation {
+                            Some(animation) if self.indefinite() => {
+                                animation.render_indeterminate(ncols, self.elapsed_time)
+                            }
+                            _ if self.segmented() => {
+                                return self.animation.render_segments(
+                                    ncols,
+                                    &self.segments,
+                                    self.total,
+                                );
+                            }
+                            _ => self.animation.render(ncols, self.percentage()),
+                        };
 
                         if let Some(colour) = &self.colour {
                             colour.apply(&render)
@@ -608,14 +1150,14 @@ impl BarExt for Bar {
             return bar_format.text().unwrap(); // this should not panic
         }
 
-        let desc = if self.desc.is_empty() {
-            "".to_owned()
-        } else {
-            self.desc.clone() + ": "
-        };
-
         #[cfg(feature = "notebook")]
         if let Some(container) = &self.container {
+            let desc = if self.desc.is_empty() {
+                "".to_owned()
+            } else {
+                self.fmt_markup(&self.desc).trim_ansi() + ": "
+            };
+
             Python::with_gil(|py| -> PyResult<()> {
                 let container = container.bind(py).getattr("children")?;
                 let container = container.downcast::<PyTuple>()?;
@@ -658,33 +1200,93 @@ impl BarExt for Bar {
             return "".to_owned();
         }
 
+        #[cfg(feature = "spinner")]
+        if let Some(spinner) = &self.spinner
+            && self.indefinite()
+        {
+            let frame = spinner.render_frame(self.elapsed_time);
+            let frame = if let Some(colour) = &self.colour {
+                colour.apply(&frame)
+            } else {
+                frame
+            };
+            let elapsed_time = format!(" [{}]", self.fmt_elapsed_time());
+            let desc = self.fmt_desc();
+            let length = frame.len_ansi() + 1 + desc.len_ansi() + self.postfix.len_ansi();
+            let mut texts = [
+                (Truncate::Desc, desc),
+                (Truncate::Postfix, self.postfix.clone()),
+            ];
+
+            self.truncate_to_fit(length + elapsed_time.len(), &mut texts);
+
+            let [(_, desc), (_, postfix)] = texts;
+            return frame + " " + &desc + &postfix + &elapsed_time;
+        }
+
         if self.indefinite() {
-            format!(
-                "{}{}{} [{}, {}{}]",
-                desc,
+            let stats = format!(
+                "{}{} [{}, {}",
                 self.fmt_counter(),
                 self.unit,
                 self.fmt_elapsed_time(),
                 self.fmt_rate(),
-                self.postfix
-            )
+            );
+            let animation_spaces = self
+                .indefinite_animation
+                .as_ref()
+                .map(|animation| animation.spaces() as usize + 1);
+            let animation_length = animation_spaces
+                .map(|spaces| self.reserved_ncols() + spaces)
+                .unwrap_or(0);
+            let (desc, postfix) = self.fit_desc_postfix(stats.len_ansi() + 1 + animation_length);
+            let rbar = stats + &postfix + "]";
+
+            let ncols = if let Some(spaces) = animation_spaces {
+                self.ncols_for_animation((desc.len_ansi() + rbar.len_ansi() + spaces) as u16)
+            } else {
+                0
+            };
+
+            if let Some(animation) = &self.indefinite_animation
+                && ncols > 0
+            {
+                desc + &animation.fmt_render_indeterminate(
+                    NonZeroU16::new(ncols).unwrap(),
+                    self.elapsed_time,
+                    &self.colour,
+                ) + " "
+                    + &rbar
+            } else {
+                desc + &rbar
+            }
         } else {
-            let lbar = desc + &self.fmt_percentage(0);
-            let rbar = format!(
-                " {}/{} [{}<{}, {}{}]",
+            let percentage = self.fmt_percentage(0);
+            let stats = format!(
+                " {}/{} [{}<{}, {}",
                 self.fmt_counter(),
                 self.fmt_total(),
                 self.fmt_elapsed_time(),
                 self.fmt_remaining_time(),
                 self.fmt_rate(),
-                self.postfix,
             );
+            let animation_length = self.reserved_ncols() + self.animation.spaces() as usize;
+            let (desc, postfix) = self
+                .fit_desc_postfix(percentage.len_ansi() + stats.len_ansi() + 1 + animation_length);
+            let lbar = desc + &percentage;
+            let rbar = stats + &postfix + "]";
 
             let ncols = self.ncols_for_animation(
                 (lbar.len_ansi() + rbar.len_ansi() + self.animation.spaces() as usize) as u16,
             );
 
-            if ncols > 0 {
+            if ncols > 0 && self.segmented() {
+                lbar + &self.animation.fmt_render_segments(
+                    NonZeroU16::new(ncols).unwrap(),
+                    &self.segments,
+                    self.total,
+                ) + &rbar
+            } else if ncols > 0 {
                 lbar + &self.animation.fmt_render(
                     NonZeroU16::new(ncols).unwrap(),
                     self.percentage(),
@@ -703,55 +1305,39 @@ impl BarExt for Bar {
 
         self.counter = 0;
         self.timer = Instant::now();
-    }
-
-    fn update(&mut self, n: usize) -> Result<bool> {
-        self.counter += n;
-        let should_refresh = self.should_refresh();
+        self.rate_history = RateHistory::default();
 
-        if should_refresh {
-            self.refresh()?;
+        for segment in &mut self.segments {
+            segment.count = 0;
         }
 
-        Ok(should_refresh)
+        self.callbacks.reset();
+    }
+
+    fn update(&mut self, n: usize) -> Result<bool> {
+        derive::update(self, n)
     }
 
     fn update_to(&mut self, n: usize) -> Result<bool> {
-        self.counter = n;
-        self.update(0)
+        derive::update_to(self, n)
     }
 
     fn write<T: Into<String>>(&mut self, text: T) -> Result<()> {
-        self.clear()?;
-        self.writer
-            .print(format!("\r{}\n", text.into()).as_bytes())?;
-
-        if self.leave {
-            self.refresh()?;
-        }
-
-        Ok(())
+        derive::write(self, text)
     }
 
     fn write_to<T: Write>(&mut self, writer: &mut T, n: Option<usize>) -> Result<bool> {
-        let text;
-
-        if let Some(n) = n {
-            self.counter += n;
+        derive::write_to(self, writer, n)
+    }
+}
 
-            if self.should_refresh() {
-                text = self.render().trim_ansi();
-            } else {
-                return Ok(false);
-            }
-        } else {
-            text = self.render().trim_ansi();
-        }
+impl derive::Hooks for Bar {
+    fn bar(&mut self) -> &mut Bar {
+        self
+    }
 
-        self.bar_length = text.len_ansi() as u16;
-        writer.write_all((text + "\n").as_bytes())?;
-        writer.flush()?;
-        Ok(true)
+    fn render(&mut self) -> String {
+        BarExt::render(self)
     }
 }
 
@@ -769,16 +1355,31 @@ pub struct BarBuilder {
     pb: Bar,
     #[cfg(feature = "template")]
     bar_format: Option<String>,
+    resume_from: Option<PathBuf>,
+    #[cfg(feature = "spinner")]
+    spinner: Option<::std::result::Result<Spinner, String>>,
+    #[cfg(feature = "template")]
+    summary_format: Option<String>,
+    #[cfg(feature = "template")]
+    title_format: Option<String>,
 }
 
 impl BarBuilder {
-    /// Prefix for progress bar.
+    /// Prefix for progress bar, which is styled using rich markup if [markup](Self::markup) is `true`.
     /// (default: `""`)
     pub fn desc<T: Into<String>>(mut self, desc: T) -> Self {
         self.pb.desc = desc.into();
         self
     }
 
+    /// Named parts of counter, which are updated using [update_segment](crate::Bar::update_segment)
+    /// and drawn with their own colours by [Animation::Segmented](crate::Animation::Segmented).
+    /// (default: `vec![]`)
+    pub fn segments(mut self, segments: Vec<Segment>) -> Self {
+        self.pb.segments = segments;
+        self
+    }
+
     /// The number of expected iterations.
     /// If `unspecified`, `iterable.size_hint().0` is used if possible.
     /// If `0`, only basic progress statistics are displayed (no ETA, no progressbar).
@@ -906,6 +1507,13 @@ impl BarBuilder {
     /// | rate        |                                                         | &#10004; (true) |
     /// | unit        |                                                         | &#10004; (true) |
     /// | postfix     |                                                         | &#10004; (true) |
+    /// | segment     | name: **name** <br>                                     | &#10004;        |
+    /// |             | description: count of segment with this name. <br>      |                 |
+    /// |             | type: string <br>                                       |                 |
+    /// | sparkline   | name: **width** <br>                                    | &#10004;        |
+    /// |             | description: number of columns of rate sparkline. <br>  |                 |
+    /// |             | type: usize <br>                                        |                 |
+    /// |             | default: 10 <br>                                        |                 |
     /// | spinner     |                                                         | &#10060;        |
     /// | animation   |                                                         | &#10060;        |
     #[cfg(feature = "template")]
@@ -915,6 +1523,60 @@ impl BarBuilder {
         self
     }
 
+    /// Report progress in terminal window title using a custom format.
+    /// Window title is only updated when progress bar display is updated and it is restored upon completion or when progress bar is dropped.
+    /// It supports all [bar_format](Self::bar_format) placeholders except `animation`.
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// // 42% build: 120/300
+    /// let pb = tqdm!(total = 300, desc = "build", title_format = "{percentage:.0}% {desc}{count}/{total}");
+    /// ```
+    #[cfg(feature = "template")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
+    pub fn title_format<T: Into<String>>(mut self, title_format: T) -> Self {
+        self.title_format = Some(title_format.into());
+        self
+    }
+
+    /// Display a summary line instead of progress bar once progress is completed.
+    /// Summary of an indefinite progress bar (`total = 0`) is displayed when it is removed from
+    /// [RowManager](crate::RowManager) or once `total` is set to `counter` and progress bar is refreshed.
+    /// It supports all [bar_format](Self::bar_format) placeholders except `animation` and following placeholders.
+    /// (default: `None`)
+    ///
+    /// | Placeholder | Description                                                                         |
+    /// |-------------|-------------------------------------------------------------------------------------|
+    /// | peak_rate   | Highest update rate, scaled if `unit_scale` is `true`.                              |
+    /// | file_size   | Counter formatted as file size, like `1.2 GB` (binary if `unit_divisor` is `1024`). |
+    /// | speed       | Average update rate formatted like `rate`, like `29.0MB/s`.                         |
+    /// | peak_speed  | Highest update rate formatted like `rate`, like `41.0MB/s`.                         |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// // download: 1.2 GB in 00:42 (avg 29.0MB/s, peak 41.0MB/s)
+    /// let pb = tqdm!(
+    ///     total = 1_200_000_000,
+    ///     desc = "download",
+    ///     unit = "B",
+    ///     unit_scale = true,
+    ///     summary_format = "{desc}{file_size} in {elapsed} (avg {speed}, peak {peak_speed})"
+    /// );
+    /// ```
+    #[cfg(feature = "template")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
+    pub fn summary_format<T: Into<String>>(mut self, summary_format: T) -> Self {
+        self.summary_format = Some(summary_format.into());
+        self
+    }
+
     /// Specify the line offset to print this progress bar (starting from `0`).
     /// Useful for managing multiple progress bars at once (eg. from threads).
     /// (default: `0`)
@@ -963,13 +1625,109 @@ impl BarBuilder {
         self
     }
 
-    /// Spinner to use with progress bar.
-    /// Spinner is only used when [bar_format](Self::bar_format) is used.
+    /// Indeterminate animation style to display progress bar when `total = 0`.
+    /// It is also used by `{animation}` placeholder of [bar_format](Self::bar_format).
+    /// Set [Bar::indefinite_animation](crate::Bar::indefinite_animation) to `None` to hide animation.
+    /// (default: [Animation::Bounce](crate::Animation::Bounce))
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// let pb = tqdm!(indefinite_animation = "knightrider");
+    /// ```
+    pub fn indefinite_animation<T: Into<Animation>>(mut self, animation: T) -> Self {
+        self.pb.indefinite_animation = Some(animation.into());
+        self
+    }
+
+    /// Spinner to use with progress bar, either a [Spinner](crate::Spinner) or name of a built-in spinner.
+    /// Unknown spinner names are reported by [build](Self::build).
+    /// If [bar_format](Self::bar_format) is not used and `total = 0`, then progress bar is displayed as
+    /// spinner followed by description, postfix and elapsed time.
+    /// Use [SteadyTick](crate::SteadyTick) to keep spinner moving without updates.
     /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(desc = "Loading", spinner = "dots");
+    /// pb.refresh().unwrap();
+    ///
+    /// assert!(kdam::Bar::builder().spinner("unknown").build().is_err());
+    /// ```
     #[cfg(feature = "spinner")]
     #[cfg_attr(docsrs, doc(cfg(feature = "spinner")))]
-    pub fn spinner(mut self, spinner: Spinner) -> Self {
-        self.pb.spinner = Some(spinner);
+    pub fn spinner<T: TryInto<Spinner>>(mut self, spinner: T) -> Self
+    where
+        T::Error: ToString,
+    {
+        self.spinner = Some(spinner.try_into().map_err(|err| err.to_string()));
+        self
+    }
+
+    /// Report progress in terminal tab/taskbar using `OSC 9;4` escape sequence with given state.
+    /// Indefinite progress is reported as [TaskbarState::Indeterminate](crate::term::TaskbarState::Indeterminate)
+    /// and progress is removed from tab/taskbar upon completion or when progress bar is cleared or dropped.
+    /// Nothing is reported when colorization is disabled (see [term::init](crate::term::init)).
+    /// (default: `None`)
+    pub fn taskbar(mut self, taskbar: TaskbarState) -> Self {
+        self.pb.taskbar = Some(taskbar);
+        self
+    }
+
+    /// Scroll description within a fixed width, if it is wider than [Marquee::width](crate::Marquee::width).
+    /// Useful for displaying long texts like file paths.
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Marquee};
+    ///
+    /// let pb = tqdm!(
+    ///     total = 100,
+    ///     desc = "/home/user/videos/2024/holidays/beach.mp4",
+    ///     marquee = Marquee::new(20, 5.0)
+    /// );
+    /// ```
+    pub fn marquee(mut self, marquee: Marquee) -> Self {
+        self.pb.marquee = Some(marquee);
+        self
+    }
+
+    /// If `true`, rich markup (see [markup](crate::term::Colorizer::markup)) is rendered in [desc](Self::desc),
+    /// messages printed using [write](crate::BarExt::write) and text columns of [RichProgress](crate::RichProgress).
+    /// (default: `false`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, desc = "[bold blue]Downloading", markup = true);
+    /// pb.write("[green]Connected[/] to server").unwrap();
+    /// ```
+    pub fn markup(mut self, markup: bool) -> Self {
+        self.pb.markup = markup;
+        self
+    }
+
+    /// Parts of progress bar display to truncate (in order of priority), when it exceeds terminal width.
+    /// (default: `[Truncate::Postfix, Truncate::Desc]`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Truncate};
+    ///
+    /// let pb = tqdm!(total = 100, truncate = vec![Truncate::Desc]);
+    /// ```
+    pub fn truncate(mut self, truncate: Vec<Truncate>) -> Self {
+        self.pb.truncate = truncate;
         self
     }
 
@@ -987,18 +1745,137 @@ impl BarBuilder {
         self
     }
 
+    /// Callback called before first update or refresh, see [Bar::on_start](crate::Bar::on_start).
+    /// (default: `None`)
+    pub fn on_start<F: Fn() + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.pb.on_start(callback);
+        self
+    }
+
+    /// Callback called with counter and delta after counter is increased, see [Bar::on_update](crate::Bar::on_update).
+    /// (default: `None`)
+    pub fn on_update<F: Fn(usize, usize) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.pb.on_update(callback);
+        self
+    }
+
+    /// Callback called with rendered text after progress bar is displayed, see [Bar::on_refresh](crate::Bar::on_refresh).
+    /// (default: `None`)
+    pub fn on_refresh<F: Fn(&str) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.pb.on_refresh(callback);
+        self
+    }
+
+    /// Callback called once when counter reaches total, see [Bar::on_complete](crate::Bar::on_complete).
+    /// (default: `None`)
+    pub fn on_complete<F: Fn() + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.pb.on_complete(callback);
+        self
+    }
+
+    /// Callback called after progress bar is reset, see [Bar::on_reset](crate::Bar::on_reset).
+    /// (default: `None`)
+    pub fn on_reset<F: Fn() + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.pb.on_reset(callback);
+        self
+    }
+
+    /// Save progress bar state to a file (see [checkpoint_to](crate::Bar::checkpoint_to)) on refresh,
+    /// if given number of seconds has passed since last saved checkpoint.
+    /// Errors while writing checkpoint are returned by [refresh](crate::BarExt::refresh).
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(
+    ///     total = 1000,
+    ///     checkpoint = ("job.checkpoint", 60.0),
+    ///     resume_from = "job.checkpoint"
+    /// );
+    ///
+    /// for _ in pb.counter..1000 {
+    ///     // ...
+    ///     pb.update(1).unwrap();
+    /// }
+    /// ```
+    pub fn checkpoint<P: AsRef<Path>>(mut self, checkpoint: (P, f32)) -> Self {
+        self.pb.checkpoint = Some((checkpoint.0.as_ref().to_owned(), checkpoint.1));
+        self
+    }
+
+    /// Restore progress bar state from a checkpoint file written by [checkpoint_to](crate::Bar::checkpoint_to).
+    /// Counter, total, description, postfix and segment counts of checkpoint override other builder options,
+    /// elapsed time and rate history are continued from checkpoint.
+    /// A missing checkpoint file is ignored, so that first run of a job starts from scratch.
+    /// (default: `None`)
+    pub fn resume_from<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.resume_from = Some(path.as_ref().to_owned());
+        self
+    }
+
     /// Build a new [Bar](crate::Bar) with custom configurations.
     ///
     /// # Note
     ///
-    /// This method only returns error when `bar_format` is used incorrectly.
+    /// This method only returns error when `bar_format`, `title_format` or `summary_format` is used incorrectly,
+    /// `spinner` name is unknown or `resume_from` checkpoint is invalid.
     #[allow(unused_mut)]
     pub fn build(mut self) -> ::std::result::Result<Bar, String> {
+        if let Some(path) = &self.resume_from {
+            match Checkpoint::read(path) {
+                Ok(checkpoint) => {
+                    self.pb.counter = checkpoint.counter;
+                    self.pb.total = checkpoint.total;
+                    self.pb.desc = checkpoint.desc;
+                    self.pb.postfix = checkpoint.postfix;
+                    self.pb.elapsed_time = checkpoint.elapsed_time;
+                    self.pb.last_checkpoint = checkpoint.elapsed_time;
+                    self.pb.rate_history = checkpoint.rate_history;
+
+                    for (name, count) in checkpoint.segments {
+                        match self.pb.segments.iter_mut().find(|x| x.name == name) {
+                            Some(segment) => segment.count = count,
+                            None => {
+                                let mut segment = Segment::new(name, "");
+                                segment.count = count;
+                                self.pb.segments.push(segment);
+                            }
+                        }
+                    }
+
+                    let elapsed_time = Duration::from_secs_f32(checkpoint.elapsed_time.max(0.0));
+                    self.pb.timer = Instant::now()
+                        .checked_sub(elapsed_time)
+                        .unwrap_or_else(Instant::now);
+                }
+                Err(err) if err.kind() == ErrorKind::NotFound => (),
+                Err(err) => return Err(err.to_string()),
+            }
+        }
+
+        #[cfg(feature = "spinner")]
+        if let Some(spinner) = self.spinner {
+            self.pb.spinner = Some(spinner?);
+        }
+
         #[cfg(feature = "template")]
         if let Some(bar_format) = self.bar_format {
             self.pb.set_bar_format(bar_format)?;
         }
 
+        #[cfg(feature = "template")]
+        if let Some(title_format) = self.title_format {
+            self.pb.set_title_format(title_format)?;
+        }
+
+        #[cfg(feature = "template")]
+        if let Some(summary_format) = self.summary_format {
+            self.pb.set_summary_format(summary_format)?;
+        }
+
         #[cfg(feature = "notebook")]
         if notebook::running() {
             Python::with_gil(|py| -> PyResult<()> {
@@ -0,0 +1,65 @@
+use std::{fmt, sync::Arc};
+
+type Callback<T> = Option<Arc<T>>;
+
+/// Callbacks fired on [Bar](crate::Bar) lifecycle events.
+#[derive(Clone, Default)]
+pub(super) struct Callbacks {
+    pub(super) on_start: Callback<dyn Fn() + Send + Sync>,
+    pub(super) on_update: Callback<dyn Fn(usize, usize) + Send + Sync>,
+    pub(super) on_refresh: Callback<dyn Fn(&str) + Send + Sync>,
+    pub(super) on_complete: Callback<dyn Fn() + Send + Sync>,
+    pub(super) on_reset: Callback<dyn Fn() + Send + Sync>,
+    started: bool,
+}
+
+impl Callbacks {
+    /// Fire `on_start` callback, if it is not fired since creation or last reset.
+    pub(super) fn start(&mut self) {
+        if !self.started {
+            self.started = true;
+
+            if let Some(on_start) = &self.on_start {
+                on_start();
+            }
+        }
+    }
+
+    pub(super) fn update(&self, counter: usize, delta: usize) {
+        if let Some(on_update) = &self.on_update {
+            on_update(counter, delta);
+        }
+    }
+
+    pub(super) fn refresh(&self, text: &str) {
+        if let Some(on_refresh) = &self.on_refresh {
+            on_refresh(text);
+        }
+    }
+
+    pub(super) fn complete(&self) {
+        if let Some(on_complete) = &self.on_complete {
+            on_complete();
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.started = false;
+
+        if let Some(on_reset) = &self.on_reset {
+            on_reset();
+        }
+    }
+}
+
+impl fmt::Debug for Callbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Callbacks")
+            .field("on_start", &self.on_start.is_some())
+            .field("on_update", &self.on_update.is_some())
+            .field("on_refresh", &self.on_refresh.is_some())
+            .field("on_complete", &self.on_complete.is_some())
+            .field("on_reset", &self.on_reset.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,150 @@
+use super::history::RateHistory;
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::Path,
+};
+
+const HEADER: &str = "kdam-checkpoint 1";
+
+/// Snapshot of progress bar state, which is stored as `key=value` lines.
+#[derive(Debug)]
+pub(super) struct Checkpoint {
+    pub(super) counter: usize,
+    pub(super) total: usize,
+    pub(super) elapsed_time: f32,
+    pub(super) desc: String,
+    pub(super) postfix: String,
+    pub(super) rate_history: RateHistory,
+    /// Names and counts of segments.
+    pub(super) segments: Vec<(String, usize)>,
+}
+
+impl Checkpoint {
+    /// Write checkpoint to a temporary file first and then rename it, so that a crash while writing
+    /// never leaves a partially written checkpoint behind.
+    pub(super) fn write(&self, path: &Path) -> Result<()> {
+        let samples = self
+            .rate_history
+            .samples
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let segments = self
+            .segments
+            .iter()
+            .map(|(name, count)| format!("segment={} {}\n", count, escape(name)))
+            .collect::<String>();
+        let text = format!(
+            "{}\ncounter={}\ntotal={}\nelapsed={}\ndesc={}\npostfix={}\nrate_history={}\npeak_rate={}\n{}",
+            HEADER,
+            self.counter,
+            self.total,
+            self.elapsed_time,
+            escape(&self.desc),
+            escape(&self.postfix),
+            samples,
+            self.rate_history.peak,
+            segments,
+        );
+
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        fs::write(&tmp_path, text)?;
+        fs::rename(&tmp_path, path)
+    }
+
+    pub(super) fn read(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("missing checkpoint header"));
+        }
+
+        let mut checkpoint = Self {
+            counter: 0,
+            total: 0,
+            elapsed_time: 0.0,
+            desc: String::new(),
+            postfix: String::new(),
+            rate_history: RateHistory::default(),
+            segments: vec![],
+        };
+
+        for line in lines {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid(&format!("invalid line {:?}", line)))?;
+
+            match key {
+                "counter" => checkpoint.counter = parse(key, value)?,
+                "total" => checkpoint.total = parse(key, value)?,
+                "elapsed" => checkpoint.elapsed_time = parse(key, value)?,
+                "desc" => checkpoint.desc = unescape(value),
+                "postfix" => checkpoint.postfix = unescape(value),
+                "rate_history" => {
+                    checkpoint.rate_history.samples = value
+                        .split_whitespace()
+                        .map(|x| parse(key, x))
+                        .collect::<Result<_>>()?
+                }
+                "peak_rate" => checkpoint.rate_history.peak = parse(key, value)?,
+                "segment" => {
+                    let (count, name) = value
+                        .split_once(' ')
+                        .ok_or_else(|| invalid(&format!("invalid value {:?} of {}", value, key)))?;
+                    checkpoint
+                        .segments
+                        .push((unescape(name), parse(key, count)?));
+                }
+                // Unknown keys are ignored for forward compatibility.
+                _ => (),
+            }
+        }
+
+        checkpoint.rate_history.last_counter = checkpoint.counter;
+        checkpoint.rate_history.last_elapsed_time = checkpoint.elapsed_time;
+        Ok(checkpoint)
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid kdam checkpoint: {}", message),
+    )
+}
+
+fn parse<T: std::str::FromStr>(key: &str, value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| invalid(&format!("invalid value {:?} of {}", value, key)))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some(c) => result.push(c),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
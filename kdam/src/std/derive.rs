@@ -0,0 +1,208 @@
+//! Runtime helpers used by [Bar](crate::Bar) and code generated by `#[derive(BarExt)]` macro.
+//!
+//! This module is not a public API, it is only exposed for derive macro.
+
+use super::{Bar, BarExt};
+use crate::term::Colorizer;
+use std::io::{Result, Write, stdin};
+
+#[cfg(feature = "notebook")]
+use super::notebook;
+
+/// Progress bar type whose [BarExt](crate::BarExt) methods are implemented using helpers of this module.
+pub trait Hooks {
+    /// Underlying progress bar.
+    fn bar(&mut self) -> &mut Bar;
+
+    /// Render progress bar text.
+    fn render(&mut self) -> String;
+
+    /// Called after counter is updated by `n`.
+    fn on_update(&mut self, _n: usize) {}
+
+    /// Called once when counter reaches total.
+    fn on_complete(&mut self) {}
+}
+
+pub fn clear<T: Hooks>(this: &mut T) -> Result<()> {
+    BarExt::clear(this.bar())
+}
+
+pub fn input<T: Hooks + BarExt, S: Into<String>>(this: &mut T, text: S) -> Result<String> {
+    BarExt::clear(this)?;
+    this.bar().writer.print(text.into().as_bytes())?;
+
+    let mut buf = String::new();
+    stdin().read_line(&mut buf)?;
+
+    if this.bar().leave {
+        BarExt::refresh(this)?;
+    }
+
+    Ok(buf)
+}
+
+pub fn refresh<T: Hooks + BarExt>(this: &mut T) -> Result<()> {
+    let pb = this.bar();
+    pb.callbacks.start();
+    pb.elapsed_time();
+    pb.autosave_checkpoint()?;
+
+    #[cfg(feature = "notebook")]
+    if notebook::running() {
+        if pb.completed() {
+            pb.total = pb.counter;
+        }
+
+        // Notebook widgets are updated by bar itself, custom rendered text is not used.
+        let text = BarExt::render(pb);
+        pb.callbacks.refresh(&text);
+        return Ok(());
+    }
+
+    if pb.completed() {
+        if !pb.leave && pb.position > 0 {
+            let escape_codes = pb.terminal_escape_codes();
+
+            if !escape_codes.is_empty() {
+                pb.writer.print(escape_codes.as_bytes())?;
+            }
+
+            return BarExt::clear(this);
+        }
+
+        pb.total = pb.counter;
+    }
+
+    let summary = pb.completed().then(|| pb.fmt_summary()).flatten();
+
+    if let Some(text) = &summary {
+        let bar_length = text.len_ansi() as u16;
+
+        // Summary may be shorter than progress bar, so remaining display is always cleared.
+        if bar_length != pb.bar_length {
+            BarExt::clear(this)?;
+            this.bar().bar_length = bar_length;
+        }
+    }
+
+    let text = match summary {
+        Some(text) => text,
+        None => {
+            let text = Hooks::render(this);
+            let bar_length = text.len_ansi() as u16;
+
+            if bar_length > this.bar().bar_length {
+                BarExt::clear(this)?;
+                this.bar().bar_length = bar_length;
+            }
+
+            text
+        }
+    };
+
+    let pb = this.bar();
+    let escape_codes = pb.terminal_escape_codes();
+    pb.writer
+        .print_at(pb.position, (escape_codes + &text).as_bytes())?;
+    pb.callbacks.refresh(&text);
+    Ok(())
+}
+
+/// Prepare progress bar before it is rendered as a part of group display like [RichGroup](crate::RichGroup),
+/// same as [refresh] does before rendering.
+#[cfg(feature = "rich")]
+pub(crate) fn before_group_render(pb: &mut Bar) -> Result<()> {
+    pb.callbacks.start();
+    pb.elapsed_time();
+    pb.autosave_checkpoint()?;
+
+    if pb.completed() {
+        pb.total = pb.counter;
+    }
+
+    Ok(())
+}
+
+/// Fire refresh callbacks of progress bar, which is rendered as a part of group display.
+#[cfg(feature = "rich")]
+pub(crate) fn after_group_render(pb: &Bar, text: &str) {
+    pb.callbacks.refresh(text);
+}
+
+pub fn reset<T: Hooks>(this: &mut T, total: Option<usize>) {
+    BarExt::reset(this.bar(), total);
+}
+
+pub fn update<T: Hooks + BarExt>(this: &mut T, n: usize) -> Result<bool> {
+    let counter = this.bar().counter + n;
+    advance(this, counter, n);
+    let should_refresh = this.bar().should_refresh();
+
+    if should_refresh {
+        BarExt::refresh(this)?;
+    }
+
+    Ok(should_refresh)
+}
+
+pub fn update_to<T: Hooks + BarExt>(this: &mut T, n: usize) -> Result<bool> {
+    let delta = n.saturating_sub(this.bar().counter);
+    advance(this, n, delta);
+    update(this, 0)
+}
+
+pub fn write<T: Hooks + BarExt, S: Into<String>>(this: &mut T, text: S) -> Result<()> {
+    BarExt::clear(this)?;
+    let pb = this.bar();
+    let text = pb.fmt_markup(&text.into());
+    pb.writer.print(format!("\r{}\n", text).as_bytes())?;
+
+    if this.bar().leave {
+        BarExt::refresh(this)?;
+    }
+
+    Ok(())
+}
+
+pub fn write_to<T: Hooks, W: Write>(
+    this: &mut T,
+    writer: &mut W,
+    n: Option<usize>,
+) -> Result<bool> {
+    if let Some(n) = n {
+        let counter = this.bar().counter + n;
+        advance(this, counter, n);
+
+        if !this.bar().should_refresh() {
+            return Ok(false);
+        }
+    }
+
+    let text = Hooks::render(this).trim_ansi();
+    let pb = this.bar();
+    pb.bar_length = text.len_ansi() as u16;
+    writer.write_all(format!("{}\n", text).as_bytes())?;
+    writer.flush()?;
+    pb.callbacks.refresh(&text);
+    Ok(true)
+}
+
+/// Set counter and fire update callbacks and hooks.
+pub(crate) fn advance<T: Hooks>(this: &mut T, counter: usize, n: usize) {
+    let pb = this.bar();
+    pb.callbacks.start();
+    let completed = pb.completed();
+    pb.counter = counter;
+    let completing = !completed && pb.completed();
+
+    if n > 0 {
+        pb.callbacks.update(counter, n);
+        this.on_update(n);
+    }
+
+    if completing {
+        this.bar().callbacks.complete();
+        this.on_complete();
+    }
+}
@@ -38,6 +38,7 @@ pub trait BarExt {
     fn update_to(&mut self, n: usize) -> Result<bool>;
 
     /// Print a message via progress bar (without overlaping with bar(s)).
+    /// Message is styled using rich markup, if [markup](crate::Bar::markup) is `true`.
     /// 
     /// Returns `Err`, if writing to handle fails.
     fn write<T: Into<String>>(&mut self, text: T) -> Result<()>;
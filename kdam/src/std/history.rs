@@ -0,0 +1,38 @@
+use std::collections::VecDeque;
+
+/// Maximum number of rate samples kept in history.
+const RATE_HISTORY_LENGTH: usize = 120;
+
+/// Minimum interval (in seconds) between two rate samples.
+const RATE_SAMPLE_INTERVAL: f32 = 1.0;
+
+/// Update rate samples of a progress bar, taken at most once per [RATE_SAMPLE_INTERVAL](RATE_SAMPLE_INTERVAL).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct RateHistory {
+    pub(super) samples: VecDeque<f32>,
+    pub(super) peak: f32,
+    pub(super) last_counter: usize,
+    pub(super) last_elapsed_time: f32,
+}
+
+impl RateHistory {
+    /// Record a rate sample, if enough time is passed since last sample.
+    pub(super) fn record(&mut self, counter: usize, elapsed_time: f32) {
+        let interval = elapsed_time - self.last_elapsed_time;
+
+        if interval < RATE_SAMPLE_INTERVAL {
+            return;
+        }
+
+        let rate = counter.saturating_sub(self.last_counter) as f32 / interval;
+
+        if self.samples.len() == RATE_HISTORY_LENGTH {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(rate);
+        self.peak = self.peak.max(rate);
+        self.last_counter = counter;
+        self.last_elapsed_time = elapsed_time;
+    }
+}
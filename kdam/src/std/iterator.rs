@@ -8,7 +8,7 @@
 */
 
 use super::{Bar, BarExt};
-use std::iter::FusedIterator;
+use std::{fmt, iter::FusedIterator};
 
 #[cfg(feature = "rayon")]
 use rayon::iter::{
@@ -17,20 +17,37 @@ use rayon::iter::{
 };
 
 #[cfg(feature = "rayon")]
-use std::sync::{Arc, Mutex};
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Maximum number of items consumed by a parallel iterator folder before its local count is flushed.
+#[cfg(feature = "rayon")]
+const BATCH_ITEMS: usize = 1000;
+
+/// Maximum interval between two flushes of local count of a parallel iterator folder.
+#[cfg(feature = "rayon")]
+const BATCH_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Progress bar shared between [BarIter](crate::BarIter) and parallel iterators.
+#[cfg(feature = "rayon")]
+type SharedBar = Arc<Mutex<Bar>>;
+
+#[cfg(not(feature = "rayon"))]
+type SharedBar = Bar;
 
 /// Iterable version of [Bar](crate::Bar).
 ///
+/// Items skipped using [nth](Iterator::nth) (e.g. by [skip](Iterator::skip)) are counted in a single update.
+///
 /// # Panics
 ///
 /// When [update](crate::BarExt::update) method returns error.
 #[derive(Debug)]
 pub struct BarIter<T> {
     inner: T,
-    #[cfg(feature = "rayon")]
-    pb: Arc<Mutex<Bar>>,
-    #[cfg(not(feature = "rayon"))]
-    pb: Bar,
+    pb: SharedBar,
 }
 
 impl<T> BarIter<T> {
@@ -46,45 +63,180 @@ impl<S, T: Iterator<Item = S>> Iterator for BarIter<T> {
         let item = self.inner.next();
 
         if item.is_some() {
-            #[cfg(feature = "rayon")]
-            self.pb.lock().unwrap().update(1).unwrap();
+            update(&mut self.pb, 1);
+        } else {
+            refresh(&mut self.pb);
+        }
 
-            #[cfg(not(feature = "rayon"))]
-            self.pb.update(1).unwrap();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let size_hint = self.inner.size_hint();
+        let item = self.inner.nth(n);
+
+        if item.is_some() {
+            update(&mut self.pb, n + 1);
         } else {
-            #[cfg(feature = "rayon")]
-            self.pb.lock().unwrap().refresh().unwrap();
+            // Number of consumed items is only known if iterator length was exact.
+            if let (lower, Some(upper)) = size_hint
+                && lower == upper
+                && lower > 0
+            {
+                update(&mut self.pb, lower);
+            }
 
-            #[cfg(not(feature = "rayon"))]
-            self.pb.refresh().unwrap();
+            refresh(&mut self.pb);
         }
 
         item
     }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let Self { inner, mut pb } = self;
+        let acc = inner.fold(init, |acc, item| {
+            update(&mut pb, 1);
+            f(acc, item)
+        });
+        refresh(&mut pb);
+        acc
+    }
 }
 
 impl<T: DoubleEndedIterator> DoubleEndedIterator for BarIter<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let item = self.inner.next();
+        let item = self.inner.next_back();
 
         if item.is_some() {
-            #[cfg(feature = "rayon")]
-            self.pb.lock().unwrap().update(1).unwrap();
+            update(&mut self.pb, 1);
+        } else {
+            refresh(&mut self.pb);
+        }
 
-            #[cfg(not(feature = "rayon"))]
-            self.pb.update(1).unwrap();
+        item
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let Self { inner, mut pb } = self;
+        let acc = inner.rfold(init, |acc, item| {
+            update(&mut pb, 1);
+            f(acc, item)
+        });
+        refresh(&mut pb);
+        acc
+    }
+}
+
+/// Iterable version of [Bar](crate::Bar), which advances counter by weight of each item.
+///
+/// See [tqdm_weighted](crate::TqdmIterator::tqdm_weighted) for an example.
+///
+/// # Panics
+///
+/// When [update](crate::BarExt::update) method returns error.
+pub struct WeightedBarIter<T, F> {
+    inner: T,
+    pb: SharedBar,
+    weight: F,
+}
+
+impl<T, F> WeightedBarIter<T, F> {
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for WeightedBarIter<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeightedBarIter")
+            .field("inner", &self.inner)
+            .field("pb", &self.pb)
+            .finish()
+    }
+}
+
+impl<S, T: Iterator<Item = S>, F: FnMut(&S) -> usize> Iterator for WeightedBarIter<T, F> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next();
+
+        if let Some(item) = &item {
+            update(&mut self.pb, (self.weight)(item));
         } else {
-            #[cfg(feature = "rayon")]
-            self.pb.lock().unwrap().refresh().unwrap();
+            refresh(&mut self.pb);
+        }
 
-            #[cfg(not(feature = "rayon"))]
-            self.pb.refresh().unwrap();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        G: FnMut(B, Self::Item) -> B,
+    {
+        let Self {
+            inner,
+            mut pb,
+            mut weight,
+        } = self;
+        let acc = inner.fold(init, |acc, item| {
+            update(&mut pb, weight(&item));
+            f(acc, item)
+        });
+        refresh(&mut pb);
+        acc
+    }
+}
+
+impl<S, T: DoubleEndedIterator<Item = S>, F: FnMut(&S) -> usize> DoubleEndedIterator
+    for WeightedBarIter<T, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next_back();
+
+        if let Some(item) = &item {
+            update(&mut self.pb, (self.weight)(item));
+        } else {
+            refresh(&mut self.pb);
         }
 
         item
     }
 }
 
+impl<S, T: FusedIterator<Item = S>, F: FnMut(&S) -> usize> FusedIterator for WeightedBarIter<T, F> {}
+
+fn update(pb: &mut SharedBar, n: usize) {
+    #[cfg(feature = "rayon")]
+    let mut pb = pb.lock().unwrap();
+
+    pb.update(n).unwrap();
+}
+
+/// Refresh progress bar once iteration is finished, unless it is disabled.
+fn refresh(pb: &mut SharedBar) {
+    #[cfg(feature = "rayon")]
+    let mut pb = pb.lock().unwrap();
+
+    if !pb.disable {
+        pb.refresh().unwrap();
+    }
+}
+
 impl<T: FusedIterator> FusedIterator for BarIter<T> {}
 
 impl<T: ExactSizeIterator> ExactSizeIterator for BarIter<T> {
@@ -93,25 +245,118 @@ impl<T: ExactSizeIterator> ExactSizeIterator for BarIter<T> {
     }
 }
 
+/// Weight of an item consumed by parallel iterator.
+#[cfg(feature = "rayon")]
+trait Weight<T>: Clone + Send {
+    fn weight(&self, item: &T) -> usize;
+}
+
+/// Weight of `1` for every item.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct Unit;
+
+#[cfg(feature = "rayon")]
+impl<T> Weight<T> for Unit {
+    fn weight(&self, _item: &T) -> usize {
+        1
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, F: Fn(&T) -> usize + Send + Sync> Weight<T> for Arc<F> {
+    fn weight(&self, item: &T) -> usize {
+        self(item)
+    }
+}
+
+/// Local count of a parallel iterator folder, which is flushed to shared progress bar
+/// every [BATCH_ITEMS](BATCH_ITEMS) items or [BATCH_INTERVAL](BATCH_INTERVAL).
+#[cfg(feature = "rayon")]
+struct Batch {
+    count: usize,
+    items: usize,
+    last_flush: Instant,
+}
+
+#[cfg(feature = "rayon")]
+impl Batch {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            items: 0,
+            last_flush: Instant::now(),
+        }
+    }
+
+    fn add(&mut self, pb: &Mutex<Bar>, n: usize) {
+        self.count += n;
+        self.items += 1;
+
+        // Clock is only read at powers of two items, so that slow items are flushed at once
+        // while fast items read the clock a few times per batch.
+        if self.items >= BATCH_ITEMS
+            || (self.items.is_power_of_two() && self.last_flush.elapsed() >= BATCH_INTERVAL)
+        {
+            self.flush(pb);
+        }
+    }
+
+    fn flush(&mut self, pb: &Mutex<Bar>) {
+        if self.count > 0 {
+            pb.lock().unwrap().update(self.count).unwrap();
+        }
+
+        self.count = 0;
+        self.items = 0;
+        self.last_flush = Instant::now();
+    }
+}
+
 #[cfg(feature = "rayon")]
-struct BarFolder<C> {
+struct BarFolder<C, W> {
     inner: C,
     pb: Arc<Mutex<Bar>>,
+    weight: W,
+    batch: Batch,
 }
 
 #[cfg(feature = "rayon")]
-impl<T, C: Folder<T>> Folder<T> for BarFolder<C> {
+impl<T, C: Folder<T>, W: Weight<T>> Folder<T> for BarFolder<C, W> {
     type Result = C::Result;
 
-    fn complete(self) -> Self::Result {
+    fn complete(mut self) -> Self::Result {
+        self.batch.flush(&self.pb);
         self.inner.complete()
     }
 
-    fn consume(self, item: T) -> Self {
-        self.pb.lock().unwrap().update(1).unwrap();
+    fn consume(mut self, item: T) -> Self {
+        self.batch.add(&self.pb, self.weight.weight(&item));
         Self {
             inner: self.inner.consume(item),
-            pb: self.pb,
+            ..self
+        }
+    }
+
+    fn consume_iter<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let Self {
+            inner,
+            pb,
+            weight,
+            mut batch,
+        } = self;
+        let inner = inner.consume_iter(
+            iter.into_iter()
+                .inspect(|item| batch.add(&pb, weight.weight(item))),
+        );
+        Self {
+            inner,
+            pb,
+            weight,
+            batch,
         }
     }
 
@@ -121,14 +366,15 @@ impl<T, C: Folder<T>> Folder<T> for BarFolder<C> {
 }
 
 #[cfg(feature = "rayon")]
-struct BarConsumer<C> {
+struct BarConsumer<C, W> {
     inner: C,
     pb: Arc<Mutex<Bar>>,
+    weight: W,
 }
 
 #[cfg(feature = "rayon")]
-impl<T, C: Consumer<T>> Consumer<T> for BarConsumer<C> {
-    type Folder = BarFolder<C::Folder>;
+impl<T, C: Consumer<T>, W: Weight<T>> Consumer<T> for BarConsumer<C, W> {
+    type Folder = BarFolder<C::Folder, W>;
     type Reducer = C::Reducer;
     type Result = C::Result;
 
@@ -140,6 +386,8 @@ impl<T, C: Consumer<T>> Consumer<T> for BarConsumer<C> {
         BarFolder {
             inner: self.inner.into_folder(),
             pb: self.pb,
+            weight: self.weight,
+            batch: Batch::new(),
         }
     }
 
@@ -149,10 +397,12 @@ impl<T, C: Consumer<T>> Consumer<T> for BarConsumer<C> {
             Self {
                 inner: left,
                 pb: self.pb.clone(),
+                weight: self.weight.clone(),
             },
             Self {
                 inner: right,
                 pb: self.pb,
+                weight: self.weight,
             },
             reducer,
         )
@@ -160,11 +410,12 @@ impl<T, C: Consumer<T>> Consumer<T> for BarConsumer<C> {
 }
 
 #[cfg(feature = "rayon")]
-impl<T, C: UnindexedConsumer<T>> UnindexedConsumer<T> for BarConsumer<C> {
+impl<T, C: UnindexedConsumer<T>, W: Weight<T>> UnindexedConsumer<T> for BarConsumer<C, W> {
     fn split_off_left(&self) -> Self {
         Self {
             inner: self.inner.split_off_left(),
             pb: self.pb.clone(),
+            weight: self.weight.clone(),
         }
     }
 
@@ -182,10 +433,47 @@ impl<S: Send, T: ParallelIterator<Item = S>> ParallelIterator for BarIter<T> {
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        self.inner.drive_unindexed(BarConsumer {
+        let mut pb = self.pb.clone();
+        let result = self.inner.drive_unindexed(BarConsumer {
             inner: consumer,
             pb: self.pb,
-        })
+            weight: Unit,
+        });
+        refresh(&mut pb);
+        result
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.inner.opt_len()
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<S, T, F> ParallelIterator for WeightedBarIter<T, F>
+where
+    S: Send,
+    T: ParallelIterator<Item = S>,
+    F: Fn(&S) -> usize + Send + Sync,
+{
+    type Item = S;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let mut pb = self.pb.clone();
+        let result = self.inner.drive_unindexed(BarConsumer {
+            inner: consumer,
+            pb: self.pb,
+            weight: Arc::new(self.weight),
+        });
+        refresh(&mut pb);
+        result
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.inner.opt_len()
     }
 }
 
@@ -197,16 +485,28 @@ struct BarProducer<T> {
 
 #[cfg(feature = "rayon")]
 impl<T, P: Producer<Item = T>> Producer for BarProducer<P> {
-    type IntoIter = BarIter<P::IntoIter>;
+    type IntoIter = BatchIter<P::IntoIter>;
     type Item = T;
 
     fn into_iter(self) -> Self::IntoIter {
-        BarIter {
+        BatchIter {
             inner: self.inner.into_iter(),
             pb: self.pb,
+            batch: Batch::new(),
         }
     }
 
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        let mut folder = self.inner.fold_with(BarFolder {
+            inner: folder,
+            pb: self.pb,
+            weight: Unit,
+            batch: Batch::new(),
+        });
+        folder.batch.flush(&folder.pb);
+        folder.inner
+    }
+
     fn max_len(&self) -> usize {
         self.inner.max_len()
     }
@@ -230,15 +530,83 @@ impl<T, P: Producer<Item = T>> Producer for BarProducer<P> {
     }
 }
 
+/// Iterator of a split of [BarProducer](BarProducer), which counts items in a local [Batch](Batch)
+/// flushed once iterator is exhausted or dropped.
+#[cfg(feature = "rayon")]
+struct BatchIter<T> {
+    inner: T,
+    pb: Arc<Mutex<Bar>>,
+    batch: Batch,
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Iterator> Iterator for BatchIter<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next();
+
+        if item.is_some() {
+            self.batch.add(&self.pb, 1);
+        } else {
+            self.batch.flush(&self.pb);
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: DoubleEndedIterator> DoubleEndedIterator for BatchIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next_back();
+
+        if item.is_some() {
+            self.batch.add(&self.pb, 1);
+        } else {
+            self.batch.flush(&self.pb);
+        }
+
+        item
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: ExactSizeIterator> ExactSizeIterator for BatchIter<T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Drop for BatchIter<T> {
+    fn drop(&mut self) {
+        // Panicking while unwinding aborts process, so poisoned lock and update errors are ignored.
+        if self.batch.count > 0
+            && let Ok(mut pb) = self.pb.lock()
+        {
+            let _ = pb.update(self.batch.count);
+        }
+    }
+}
+
 #[cfg(feature = "rayon")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
 impl<S: Send, T: IndexedParallelIterator<Item = S>> IndexedParallelIterator for BarIter<T> {
     fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let mut pb = self.pb.clone();
         let consumer = BarConsumer {
             inner: consumer,
             pb: self.pb,
+            weight: Unit,
         };
-        self.inner.drive(consumer)
+        let result = self.inner.drive(consumer);
+        refresh(&mut pb);
+        result
     }
 
     fn len(&self) -> usize {
@@ -246,10 +614,13 @@ impl<S: Send, T: IndexedParallelIterator<Item = S>> IndexedParallelIterator for
     }
 
     fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
-        return self.inner.with_producer(Callback {
+        let mut pb = self.pb.clone();
+        let output = self.inner.with_producer(Callback {
             inner: callback,
             pb: self.pb,
         });
+        refresh(&mut pb);
+        return output;
 
         struct Callback<CB> {
             inner: CB,
@@ -297,6 +668,88 @@ where
 
     /// Decorate any sized iterator to [BarIter](crate::BarIter) with existing [Bar](crate::Bar).
     fn tqdm_with_bar(self, pb: Bar) -> BarIter<Self>;
+
+    /// Decorate any iterator to [WeightedBarIter](crate::WeightedBarIter),
+    /// which advances counter by weight of each item instead of `1`.
+    ///
+    /// Total is unknown, use [tqdm_weighted_with_bar](Self::tqdm_weighted_with_bar) to specify total weight
+    /// or [tqdm_weighted_sized](Self::tqdm_weighted_sized) to compute it from a clone of iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, TqdmIterator};
+    ///
+    /// let chunks = vec![vec![0_u8; 100], vec![0_u8; 50], vec![0_u8; 250]];
+    ///
+    /// for chunk in chunks.iter().tqdm_weighted(|chunk| chunk.len()) {
+    ///     // ...
+    /// }
+    ///
+    /// // Total weight computed up-front.
+    /// let total = chunks.iter().map(|chunk| chunk.len()).sum();
+    ///
+    /// for chunk in chunks.iter().tqdm_weighted_with_bar(tqdm!(total = total), |chunk| chunk.len()) {
+    ///     // ...
+    /// }
+    ///
+    /// eprintln!();
+    /// ```
+    fn tqdm_weighted<F: FnMut(&Self::Item) -> usize>(self, weight: F) -> WeightedBarIter<Self, F> {
+        Self::tqdm_weighted_with_bar(self, Bar::default(), weight)
+    }
+
+    /// Decorate any iterator to [WeightedBarIter](crate::WeightedBarIter) with existing [Bar](crate::Bar),
+    /// whose total is total weight of all items (`0` if unknown).
+    fn tqdm_weighted_with_bar<F: FnMut(&Self::Item) -> usize>(
+        self,
+        pb: Bar,
+        weight: F,
+    ) -> WeightedBarIter<Self, F>;
+
+    /// Decorate any cloneable iterator to [WeightedBarIter](crate::WeightedBarIter),
+    /// whose total is computed up-front by summing weights of all items of a clone of iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::TqdmIterator;
+    ///
+    /// let chunks = vec![vec![0_u8; 100], vec![0_u8; 50], vec![0_u8; 250]];
+    /// // Total is 400.
+    /// for chunk in chunks.iter().tqdm_weighted_sized(|chunk| chunk.len()) {
+    ///     // ...
+    /// }
+    ///
+    /// eprintln!();
+    /// ```
+    fn tqdm_weighted_sized<F: FnMut(&Self::Item) -> usize>(
+        self,
+        weight: F,
+    ) -> WeightedBarIter<Self, F>
+    where
+        Self: Clone,
+    {
+        Self::tqdm_weighted_sized_with_bar(self, Bar::default(), weight)
+    }
+
+    /// Decorate any cloneable iterator to [WeightedBarIter](crate::WeightedBarIter) with existing [Bar](crate::Bar).
+    ///
+    /// If total of progress bar is `0`, it is computed up-front by summing weights of all items of a clone of iterator.
+    fn tqdm_weighted_sized_with_bar<F: FnMut(&Self::Item) -> usize>(
+        self,
+        mut pb: Bar,
+        mut weight: F,
+    ) -> WeightedBarIter<Self, F>
+    where
+        Self: Clone,
+    {
+        if pb.indefinite() {
+            pb.total = self.clone().map(|x| weight(&x)).sum();
+        }
+
+        Self::tqdm_weighted_with_bar(self, pb, weight)
+    }
 }
 
 impl<S, T: Iterator<Item = S>> TqdmIterator for T {
@@ -313,6 +766,21 @@ impl<S, T: Iterator<Item = S>> TqdmIterator for T {
             pb,
         }
     }
+
+    fn tqdm_weighted_with_bar<F: FnMut(&S) -> usize>(
+        self,
+        pb: Bar,
+        weight: F,
+    ) -> WeightedBarIter<Self, F> {
+        WeightedBarIter {
+            inner: self,
+            #[cfg(feature = "rayon")]
+            pb: Arc::new(Mutex::new(pb)),
+            #[cfg(not(feature = "rayon"))]
+            pb,
+            weight,
+        }
+    }
 }
 
 /// Parallel iterators decoration with [BarIter](crate::BarIter).
@@ -321,36 +789,136 @@ pub trait TqdmParallelIterator
 where
     Self: ParallelIterator + Sized,
 {
-    /// Decorate any sized parallel iterator to [BarIter](crate::BarIter).
-    fn tqdm(self) -> BarIter<Self>
+    /// Decorate any parallel iterator to [BarIter](crate::BarIter).
+    ///
+    /// Total is length of parallel iterator, if it is known (e.g. indexed parallel iterators).
+    /// Otherwise progress bar is indefinite, use [tqdm_with_bar](Self::tqdm_with_bar) to specify total.
+    /// Progress bar is refreshed once after parallel operation finishes, unless it is disabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{rayon::prelude::*, tqdm, TqdmParallelIterator};
+    ///
+    /// let evens = (0..1000)
+    ///     .into_par_iter()
+    ///     .filter(|x| x % 2 == 0)
+    ///     .tqdm()
+    ///     .count();
+    ///
+    /// let sum = (0..1000)
+    ///     .par_bridge()
+    ///     .tqdm_with_bar(tqdm!(total = 1000))
+    ///     .sum::<usize>();
+    ///
+    /// eprintln!();
+    /// ```
+    fn tqdm(self) -> BarIter<Self> {
+        Self::tqdm_with_bar(self, Bar::default())
+    }
+
+    /// Decorate any parallel iterator to [BarIter](crate::BarIter) with existing [Bar](crate::Bar).
+    ///
+    /// If total of progress bar is `0`, length of parallel iterator is used as total when it is known.
+    /// Counter is updated in batches by each thread, so update callbacks are called far less often than once per item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{rayon::prelude::*, tqdm, Bar, TqdmParallelIterator};
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// // Returns progress bar counting number of `on_update` calls and sum of their deltas.
+    /// fn counted_bar() -> (Bar, Arc<[AtomicUsize; 2]>) {
+    ///     let stats = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+    ///     let stats_clone = stats.clone();
+    ///     let mut pb = tqdm!(total = 100_000);
+    ///     pb.on_update(move |_, n| {
+    ///         stats_clone[0].fetch_add(1, Ordering::Relaxed);
+    ///         stats_clone[1].fetch_add(n, Ordering::Relaxed);
+    ///     });
+    ///     (pb, stats)
+    /// }
+    ///
+    /// let (pb, stats) = counted_bar();
+    /// (0..100_000)
+    ///     .into_par_iter()
+    ///     .tqdm_with_bar(pb)
+    ///     .enumerate()
+    ///     .for_each(|_| ());
+    /// assert!(stats[0].load(Ordering::Relaxed) < 10_000);
+    /// assert_eq!(stats[1].load(Ordering::Relaxed), 100_000);
+    ///
+    /// let (pb, stats) = counted_bar();
+    /// (0..100_000)
+    ///     .into_par_iter()
+    ///     .tqdm_with_bar(pb)
+    ///     .zip(0..100_000)
+    ///     .for_each(|_| ());
+    /// assert!(stats[0].load(Ordering::Relaxed) < 10_000);
+    /// assert_eq!(stats[1].load(Ordering::Relaxed), 100_000);
+    ///
+    /// eprintln!();
+    /// ```
+    fn tqdm_with_bar(self, pb: Bar) -> BarIter<Self>;
+
+    /// Decorate any parallel iterator to [WeightedBarIter](crate::WeightedBarIter),
+    /// which advances counter by weight of each item instead of `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{rayon::prelude::*, tqdm, TqdmParallelIterator};
+    ///
+    /// let chunks = vec![vec![0_u8; 100], vec![0_u8; 50], vec![0_u8; 250]];
+    /// let total = chunks.iter().map(|chunk| chunk.len()).sum();
+    ///
+    /// let sizes = chunks
+    ///     .par_iter()
+    ///     .tqdm_weighted_with_bar(tqdm!(total = total), |chunk| chunk.len())
+    ///     .map(|chunk| chunk.len())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// eprintln!();
+    /// ```
+    fn tqdm_weighted<F>(self, weight: F) -> WeightedBarIter<Self, F>
     where
-        Self: IndexedParallelIterator,
+        F: Fn(&Self::Item) -> usize + Send + Sync,
     {
-        Self::tqdm_with_bar(self, Bar::default())
+        Self::tqdm_weighted_with_bar(self, Bar::default(), weight)
     }
 
-    /// Decorate any sized parallel iterator to [BarIter](crate::BarIter) with existing [Bar](crate::Bar).
-    fn tqdm_with_bar(self, pb: Bar) -> BarIter<Self>
+    /// Decorate any parallel iterator to [WeightedBarIter](crate::WeightedBarIter) with existing [Bar](crate::Bar),
+    /// whose total is total weight of all items (`0` if unknown).
+    fn tqdm_weighted_with_bar<F>(self, pb: Bar, weight: F) -> WeightedBarIter<Self, F>
     where
-        Self: IndexedParallelIterator;
+        F: Fn(&Self::Item) -> usize + Send + Sync;
 }
 
 #[cfg(feature = "rayon")]
 impl<S, T: ParallelIterator<Item = S>> TqdmParallelIterator for T {
-    fn tqdm_with_bar(self, mut pb: Bar) -> BarIter<Self>
-    where
-        Self: IndexedParallelIterator,
-    {
-        if pb.indefinite() {
-            pb.total = self.len();
+    fn tqdm_with_bar(self, mut pb: Bar) -> BarIter<Self> {
+        if let Some(len) = self.opt_len().filter(|_| pb.indefinite()) {
+            pb.total = len;
         }
 
         BarIter {
             inner: self,
-            #[cfg(feature = "rayon")]
             pb: Arc::new(Mutex::new(pb)),
-            #[cfg(not(feature = "rayon"))]
-            pb,
+        }
+    }
+
+    fn tqdm_weighted_with_bar<F>(self, pb: Bar, weight: F) -> WeightedBarIter<Self, F>
+    where
+        F: Fn(&S) -> usize + Send + Sync,
+    {
+        WeightedBarIter {
+            inner: self,
+            pb: Arc::new(Mutex::new(pb)),
+            weight,
         }
     }
 }
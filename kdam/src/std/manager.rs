@@ -1,4 +1,4 @@
-use super::{Bar, BarExt};
+use super::{Bar, BarExt, Tick};
 use std::{collections::HashSet, io::Result};
 
 /// RowManager allows to store and update many progress bars.
@@ -121,7 +121,8 @@ impl RowManager {
 
         if !*disable && pb.completed() {
             if pb.leave {
-                let text = pb.render();
+                pb.elapsed_time();
+                let text = pb.fmt_summary().unwrap_or_else(|| pb.render());
                 pb.writer.print(format!("\r{}\n", text).as_bytes())?;
             }
 
@@ -159,21 +160,22 @@ impl RowManager {
             )?;
 
             for (bar, disable) in self.bars.iter_mut() {
-                if !*disable && !bar.completed() {
-                    if let Some(pos) = self.avaliable_pos.iter().min() {
-                        if bar.disable && bar.position != *pos {
-                            bar.position = *pos;
-
-                            if self.nrows > bar.position {
-                                bar.disable = false;
-                            }
+                if !*disable
+                    && !bar.completed()
+                    && let Some(pos) = self.avaliable_pos.iter().min()
+                    && bar.disable
+                    && bar.position != *pos
+                {
+                    bar.position = *pos;
+
+                    if self.nrows > bar.position {
+                        bar.disable = false;
+                    }
 
-                            bar.refresh()?;
+                    bar.refresh()?;
 
-                            if self.avaliable_pos.remove(&bar.position) {
-                                self.acquired_pos.insert(bar.position);
-                            }
-                        }
+                    if self.avaliable_pos.remove(&bar.position) {
+                        self.acquired_pos.insert(bar.position);
                     }
                 }
             }
@@ -184,11 +186,23 @@ impl RowManager {
 
     /// Removes a progress bar and returns it.
     ///
+    /// Completion summary of a visible indefinite progress bar (`total = 0`) is displayed,
+    /// if [summary_format](crate::BarBuilder::summary_format) is set and `leave` is `true`.
+    ///
     /// # Panics
     ///
     /// If `index` is out of bounds.
     pub fn remove(&mut self, index: usize) -> Bar {
-        let (pb, _) = self.bars.remove(index);
+        let (mut pb, disable) = self.bars.remove(index);
+
+        if !disable && !pb.disable && pb.indefinite() && pb.leave {
+            pb.elapsed_time();
+
+            if let Some(text) = pb.fmt_summary() {
+                let _ = pb.clear();
+                let _ = pb.writer.print_at(pb.position, text.as_bytes());
+            }
+        }
 
         if self.acquired_pos.remove(&pb.position) {
             self.avaliable_pos.insert(pb.position);
@@ -197,3 +211,16 @@ impl RowManager {
         pb
     }
 }
+
+impl Tick for RowManager {
+    fn tick(&mut self) -> bool {
+        let mut ticking = false;
+
+        for (pb, _) in self.bars.iter_mut().filter(|(pb, _)| !pb.completed()) {
+            pb.tick();
+            ticking = true;
+        }
+
+        ticking
+    }
+}
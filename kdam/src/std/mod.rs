@@ -1,16 +1,25 @@
 mod bar;
+mod callbacks;
+mod checkpoint;
 mod extension;
+mod history;
 mod iterator;
 mod manager;
 mod styles;
+mod ticker;
+mod truncate;
 
 pub mod monitor;
 
+pub(crate) mod derive;
+
 pub use bar::{Bar, BarBuilder};
 pub use extension::BarExt;
-pub use iterator::{BarIter, TqdmIterator};
+pub use iterator::{BarIter, TqdmIterator, WeightedBarIter};
 pub use manager::RowManager;
-pub use styles::{Animation, Colour};
+pub use styles::{Animation, Colour, Marquee, Segment};
+pub use ticker::{SteadyTick, Tick};
+pub use truncate::Truncate;
 
 #[cfg(feature = "notebook")]
 mod notebook;
@@ -1,6 +1,7 @@
 //! Monitor mode for progress bars.
 //!
 //! In monitor mode progress bar is refreshed in specific intervals.
+//! [Monitor](Monitor) can refresh many progress bars from one thread and detect stalled progress bars.
 //! Default monitor modes may not fit in many cases.
 //! So, it is recommended to create a custom monitor mode.
 //! The basic idea behind monitor mode is to create a separate thread for updating progress bar
@@ -32,14 +33,268 @@
 //! }
 //! ```
 
-use crate::std::{Bar, BarExt};
+use crate::std::{Bar, BarExt, Colour};
 use std::{
-    sync::{Arc, Mutex},
+    mem,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
     thread,
+    time::{Duration, Instant},
 };
 
+type MonitorTask = Box<dyn FnMut() -> bool + Send>;
+type StallCallback = Arc<dyn Fn(&mut Bar) + Send + Sync>;
+
+#[derive(Default)]
+struct Tasks {
+    queued: Vec<MonitorTask>,
+    /// Number of tasks taken out by monitor thread.
+    running: usize,
+}
+
+/// Monitor thread for refreshing many progress bars in specific intervals.
+///
+/// Progress bars are also checked for stalls i.e. no counter change for [stall_timeout](Self::stall_timeout) seconds.
+/// Stalled progress bars are displayed using [stall_colour](Self::stall_colour) and [stall_postfix](Self::stall_postfix),
+/// which are restored once counter changes again.
+///
+/// Monitor thread is stopped when [stop](Self::stop) is called or monitor is dropped.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{monitor::Monitor, tqdm, BarExt};
+///
+/// let monitor = Monitor::new(0.1)
+///     .stall_timeout(5.0)
+///     .stall_colour("red")
+///     .stall_postfix("stalled")
+///     .on_stall(|pb| pb.write(format!("{} stalled at {}", pb.desc, pb.counter)).unwrap());
+///
+/// let pb1 = monitor.add_bar(tqdm!(total = 100, position = 0));
+/// // For RichProgress: monitor.add(rich_pb, |x| &mut x.pb)
+/// let pb2 = monitor.add(tqdm!(position = 1), |x| x);
+///
+/// for _ in 0..100 {
+///     pb1.lock().unwrap().update(1).unwrap();
+///     pb2.lock().unwrap().update(1).unwrap();
+/// }
+///
+/// monitor.stop();
+/// eprint!("\n\n");
+/// ```
+pub struct Monitor {
+    handle: Option<thread::JoinHandle<()>>,
+    stop: Arc<AtomicBool>,
+    tasks: Arc<Mutex<Tasks>>,
+    // Stall Fields
+    on_stall: Option<StallCallback>,
+    stall_colour: Option<Colour>,
+    stall_postfix: Option<String>,
+    stall_timeout: Option<Duration>,
+}
+
+impl Monitor {
+    // -----------------------------------------------------------------------------------------
+    // Constructors
+    // -----------------------------------------------------------------------------------------
+
+    /// Create a new [Monitor](Self) and start monitor thread,
+    /// which refreshes progress bars every `maxinterval` seconds.
+    pub fn new(maxinterval: f32) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let tasks = Arc::new(Mutex::new(Tasks::default()));
+        let stop_clone = stop.clone();
+        let tasks_clone = tasks.clone();
+
+        let handle = thread::spawn(move || {
+            while !stop_clone.load(Ordering::Acquire) {
+                thread::park_timeout(Duration::from_secs_f32(maxinterval));
+
+                if stop_clone.load(Ordering::Acquire) {
+                    break;
+                }
+
+                // Tasks are run without holding lock, so that progress bars can be added,
+                // counted or locked by other threads (or stall callbacks) meanwhile.
+                let mut running = {
+                    let mut tasks = tasks_clone.lock().unwrap();
+                    tasks.running = tasks.queued.len();
+                    mem::take(&mut tasks.queued)
+                };
+
+                running.retain_mut(|task| task());
+
+                let mut tasks = tasks_clone.lock().unwrap();
+                running.append(&mut tasks.queued);
+                tasks.queued = running;
+                tasks.running = 0;
+            }
+        });
+
+        Self {
+            handle: Some(handle),
+            stop,
+            tasks,
+            on_stall: None,
+            stall_colour: None,
+            stall_postfix: None,
+            stall_timeout: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------------------------
+    // Setters
+    // -----------------------------------------------------------------------------------------
+
+    /// Number of seconds without any counter change after which a progress bar is considered stalled.
+    /// Stalls are not detected if it is not set.
+    /// (default: `None`)
+    pub fn stall_timeout(mut self, timeout: f32) -> Self {
+        self.stall_timeout = Some(Duration::from_secs_f32(timeout));
+        self
+    }
+
+    /// Colour of stalled progress bars.
+    /// (default: `None`)
+    pub fn stall_colour<T: Into<Colour>>(mut self, colour: T) -> Self {
+        self.stall_colour = Some(colour.into());
+        self
+    }
+
+    /// Postfix of stalled progress bars.
+    /// (default: `None`)
+    pub fn stall_postfix<T: Into<String>>(mut self, postfix: T) -> Self {
+        self.stall_postfix = Some(postfix.into());
+        self
+    }
+
+    /// Callback called once with progress bar when it stalls.
+    /// It is called from monitor thread while progress bar (but not monitor) is locked.
+    /// Changes made to progress bar by callback are not restored when progress bar resumes.
+    /// (default: `None`)
+    pub fn on_stall<F: Fn(&mut Bar) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.on_stall = Some(Arc::new(callback));
+        self
+    }
+
+    // -----------------------------------------------------------------------------------------
+    // Methods
+    // -----------------------------------------------------------------------------------------
+
+    /// Monitor a progress bar returning back shared reference to it.
+    ///
+    /// Progress bar is monitored until it is completed or all references of it are dropped.
+    pub fn add_bar(&self, pb: Bar) -> Arc<Mutex<Bar>> {
+        self.add(pb, |x| x)
+    }
+
+    /// Monitor any progress bar type (e.g. [RichProgress](crate::RichProgress) or types deriving [BarExt](crate::BarExt)),
+    /// returning back shared reference to it.
+    /// `bar` function should return underlying [Bar](crate::Bar) of progress bar.
+    ///
+    /// Progress bar is monitored until it is completed or all references of it are dropped.
+    pub fn add<T: BarExt + Send + 'static>(
+        &self,
+        pb: T,
+        bar: fn(&mut T) -> &mut Bar,
+    ) -> Arc<Mutex<T>> {
+        let pb_arc = Arc::new(Mutex::new(pb));
+        let pb_arc_clone = pb_arc.clone();
+        let on_stall = self.on_stall.clone();
+        let stall_colour = self.stall_colour.clone();
+        let stall_postfix = self.stall_postfix.clone();
+        let stall_timeout = self.stall_timeout;
+        let mut last_counter = 0;
+        let mut last_change = Instant::now();
+        // Original colour and postfix of stalled progress bar.
+        let mut stalled: Option<(Option<Colour>, String)> = None;
+
+        self.tasks.lock().unwrap().queued.push(Box::new(move || {
+            if Arc::strong_count(&pb_arc_clone) == 1 {
+                return false;
+            }
+
+            let mut pb = pb_arc_clone.lock().unwrap();
+            let pb_bar = bar(&mut pb);
+
+            if pb_bar.counter != last_counter || pb_bar.completed() {
+                last_counter = pb_bar.counter;
+                last_change = Instant::now();
+
+                if let Some((colour, postfix)) = stalled.take() {
+                    pb_bar.colour = colour;
+                    pb_bar.postfix = postfix;
+                }
+
+                if pb_bar.completed() {
+                    return false;
+                }
+            } else if let Some(timeout) = stall_timeout
+                && stalled.is_none()
+                && last_change.elapsed() >= timeout
+            {
+                stalled = Some((pb_bar.colour.clone(), pb_bar.postfix.clone()));
+
+                if let Some(colour) = &stall_colour {
+                    pb_bar.colour = Some(colour.clone());
+                }
+
+                if let Some(postfix) = &stall_postfix {
+                    pb_bar.set_postfix(postfix.clone());
+                }
+
+                if let Some(on_stall) = &on_stall {
+                    on_stall(pb_bar);
+                }
+            }
+
+            let _ = pb.refresh();
+            true
+        }));
+
+        pb_arc
+    }
+
+    /// Returns number of progress bars being monitored.
+    pub fn len(&self) -> usize {
+        let tasks = self.tasks.lock().unwrap();
+        tasks.queued.len() + tasks.running
+    }
+
+    /// Returns `true` if no progress bar is being monitored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stop monitor thread and wait for it to exit.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Release);
+
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
 /// Monitor mode for [Bar](crate::Bar).
 ///
+/// Monitor thread exits once progress is completed or all clones of returned bar are dropped.
+/// See [Monitor](Monitor) for monitoring many progress bars with a stop handle.
+///
 /// # Example
 ///
 /// ```no_run
@@ -58,17 +313,23 @@ use std::{
 /// ```
 pub fn bar(pb: Bar, maxinterval: f32) -> (Arc<Mutex<Bar>>, thread::JoinHandle<()>) {
     let pb_arc = Arc::new(Mutex::new(pb));
-    let pb_arc_clone = pb_arc.clone();
+    let pb_weak = Arc::downgrade(&pb_arc);
 
-    let handle = thread::spawn(move || loop {
-        thread::sleep(std::time::Duration::from_secs_f32(maxinterval));
-        let mut pb_monitor = pb_arc_clone.lock().unwrap();
+    let handle = thread::spawn(move || {
+        loop {
+            thread::sleep(Duration::from_secs_f32(maxinterval));
 
-        if pb_monitor.completed() {
-            break;
-        }
+            let Some(pb_arc) = pb_weak.upgrade() else {
+                break;
+            };
+            let mut pb_monitor = pb_arc.lock().unwrap();
+
+            if pb_monitor.completed() {
+                break;
+            }
 
-        let _ = pb_monitor.refresh();
+            let _ = pb_monitor.refresh();
+        }
     });
 
     (pb_arc, handle)
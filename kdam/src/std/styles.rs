@@ -1,9 +1,9 @@
-use crate::{term::Colorizer, utils::divmod};
+use crate::{
+    term::Colorizer,
+    utils::{self, divmod},
+};
 use std::num::NonZeroU16;
 
-#[cfg(feature = "gradient")]
-use crate::utils;
-
 #[cfg(feature = "gradient")]
 use colorgrad::{Gradient, GradientBuilder, LinearGradient};
 
@@ -20,15 +20,33 @@ const BAR_TQDM: [&str; 8] = [
     "\u{258F}", "\u{258E}", "\u{258D}", "\u{258C}", "\u{258B}", "\u{258A}", "\u{2589}", "\u{2588}",
 ];
 const BAR_TQDM_ASCII: [&str; 10] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "#"];
+const BAR_SHADES: [&str; 4] = ["\u{2588}", "\u{2593}", "\u{2592}", "\u{2591}"];
+const BAR_PULSE: [&str; 8] = [
+    " ", "\u{2591}", "\u{2592}", "\u{2593}", "\u{2588}", "\u{2593}", "\u{2592}", "\u{2591}",
+];
 
 /// Animation styles for [Bar](crate::Bar).
+///
+/// [Bounce](Self::Bounce), [KnightRider](Self::KnightRider) and [Pulse](Self::Pulse) are indeterminate animations,
+/// which are used to display progress when total is unknown (see [render_indeterminate](Self::render_indeterminate)).
 #[derive(Clone, Debug)]
 pub enum Animation {
     Arrow,
+    /// Indeterminate animation of a `<=>` block bouncing back and forth.
+    Bounce,
     Classic,
     Custom(Vec<String>, Option<String>),
     FillUp,
     FiraCode,
+    /// Indeterminate animation of a block with fading trail bouncing back and forth.
+    KnightRider,
+    /// Indeterminate animation of shaded waves moving from left to right.
+    Pulse,
+    /// [Tqdm](Self::Tqdm) animation split into coloured [segments](crate::Bar::segments), like passed, failed and skipped.
+    ///
+    /// Each segment is drawn proportionally to its count with sub-cell precision,
+    /// where a cell shared by two segments uses colour of next segment as background.
+    Segmented,
     Tqdm,
     TqdmAscii,
 }
@@ -51,8 +69,15 @@ impl Animation {
         )
     }
 
+    /// Returns `true` if animation is an indeterminate animation.
+    pub fn is_indeterminate(&self) -> bool {
+        matches!(self, Self::Bounce | Self::KnightRider | Self::Pulse)
+    }
+
     /// Render progress bar animation.
     ///
+    /// For indeterminate animations, `progress` is position within one animation cycle.
+    ///
     /// # Arguments
     ///
     /// - *ncols*: Number of columns to render.
@@ -63,6 +88,51 @@ impl Animation {
         let ncols = ncols.get();
 
         match self {
+            Self::Bounce | Self::KnightRider => {
+                let (block, trail): (&[&str], &[&str]) = match self {
+                    Self::Bounce => (&["<", "=", ">"], &[]),
+                    _ => (&BAR_SHADES[..1], &BAR_SHADES[1..]),
+                };
+
+                let block_length = block.len().min(ncols as usize);
+                let travel = (ncols as usize - block_length) as f32;
+                let forward = progress < 0.5;
+                let position = if forward {
+                    travel * progress * 2.0
+                } else {
+                    travel * (1.0 - progress) * 2.0
+                }
+                .round() as usize;
+
+                (0..ncols as usize)
+                    .map(|i| {
+                        if (position..(position + block_length)).contains(&i) {
+                            block[i - position]
+                        } else {
+                            // Trail is drawn behind block in direction of movement.
+                            let distance = if forward {
+                                position.checked_sub(i + 1)
+                            } else {
+                                i.checked_sub(position + block_length)
+                            };
+
+                            distance
+                                .and_then(|distance| trail.get(distance))
+                                .unwrap_or(&" ")
+                        }
+                    })
+                    .collect()
+            }
+
+            Self::Pulse => {
+                let npulse = BAR_PULSE.len();
+                let offset = npulse - (progress * npulse as f32) as usize % npulse;
+
+                (0..ncols as usize)
+                    .map(|i| BAR_PULSE[(i + offset) % npulse])
+                    .collect()
+            }
+
             Self::Arrow | Self::Classic => {
                 let block = (ncols as f32 * progress) as u16;
 
@@ -122,16 +192,152 @@ impl Animation {
         }
     }
 
+    /// Render indeterminate progress bar animation at given time.
+    ///
+    /// Animations which are not indeterminate are rendered as [Bounce](Self::Bounce) animation.
+    ///
+    /// # Arguments
+    ///
+    /// - *ncols*: Number of columns to render.
+    /// - *elapsed_time*: Time (in seconds) since progress started.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    /// use std::num::NonZeroU16;
+    ///
+    /// let ncols = NonZeroU16::new(10).unwrap();
+    /// assert_eq!(Animation::Bounce.render_indeterminate(ncols, 0.0), "<=>       ");
+    /// assert_eq!(Animation::Bounce.render_indeterminate(ncols, 1.0), "       <=>");
+    /// assert_eq!(Animation::KnightRider.render_indeterminate(ncols, 0.5), "  ░▒▓█    ");
+    /// ```
+    pub fn render_indeterminate(&self, ncols: NonZeroU16, elapsed_time: f32) -> String {
+        let period = match self {
+            Self::Pulse => 0.5,
+            _ => 2.0,
+        };
+        let progress = (elapsed_time.max(0.0) / period).fract();
+
+        if self.is_indeterminate() {
+            self.render(ncols, progress)
+        } else {
+            Self::Bounce.render(ncols, progress)
+        }
+    }
+
+    /// Render segmented progress bar animation, where each segment is drawn proportionally to its count
+    /// with [colour](Segment::colour) of segment. If `total` is `0`, segments fill whole bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{Animation, Segment};
+    /// use std::num::NonZeroU16;
+    ///
+    /// let segments = [Segment::new("passed", ""), Segment::new("failed", "")];
+    /// let mut segments = segments.to_vec();
+    /// segments[0].count = 3;
+    /// segments[1].count = 1;
+    ///
+    /// let ncols = NonZeroU16::new(8).unwrap();
+    /// assert_eq!(Animation::Segmented.render_segments(ncols, &segments, 8), "████    ");
+    /// ```
+    pub fn render_segments(&self, ncols: NonZeroU16, segments: &[Segment], total: usize) -> String {
+        let nsyms = BAR_TQDM.len();
+        let units = ncols.get() as usize * nsyms;
+        let count = segments.iter().map(|x| x.count).sum::<usize>();
+        let total = if total == 0 { count } else { total };
+
+        if total == 0 {
+            return " ".repeat(ncols.get() as usize);
+        }
+
+        // Units covered upto end of each segment.
+        let mut covered = 0;
+        let ends = segments
+            .iter()
+            .map(|segment| {
+                covered = (covered + segment.count).min(total);
+                (covered as f64 / total as f64 * units as f64).round() as usize
+            })
+            .collect::<Vec<_>>();
+
+        let mut runs: Vec<(String, String)> = vec![];
+
+        for cell in 0..ncols.get() as usize {
+            let start = cell * nsyms;
+            let (glyph, style) = match ends.iter().position(|end| *end > start) {
+                Some(index) if ends[index] >= start + nsyms => {
+                    (BAR_TQDM[nsyms - 1], segments[index].colour.clone())
+                }
+                Some(index) => {
+                    let fg = &segments[index].colour;
+                    let bg = ends[(index + 1)..]
+                        .iter()
+                        .position(|end| *end > ends[index])
+                        .map(|next| &segments[index + 1 + next].colour)
+                        .filter(|bg| !bg.is_empty());
+
+                    let style = match bg {
+                        Some(bg) if fg.is_empty() => format!("on {}", bg),
+                        Some(bg) => format!("{} on {}", fg, bg),
+                        None => fg.clone(),
+                    };
+                    (BAR_TQDM[ends[index] - start - 1], style)
+                }
+                None => (" ", String::new()),
+            };
+
+            match runs.last_mut() {
+                Some((last_style, text)) if *last_style == style => *text += glyph,
+                _ => runs.push((style, glyph.to_owned())),
+            }
+        }
+
+        runs.iter()
+            .map(|(style, text)| {
+                if style.is_empty() {
+                    text.clone()
+                } else {
+                    text.colorize(style)
+                }
+            })
+            .collect()
+    }
+
+    /// Render segmented progress bar animation with opening and closing brackets.
+    pub fn fmt_render_segments(
+        &self,
+        ncols: NonZeroU16,
+        segments: &[Segment],
+        total: usize,
+    ) -> String {
+        self.fmt_brackets(self.render_segments(ncols, segments, total), &None)
+    }
+
     /// Render progress bar animation with opening and closing brackets.
     pub fn fmt_render(&self, ncols: NonZeroU16, progress: f32, colour: &Option<Colour>) -> String {
+        self.fmt_brackets(self.render(ncols, progress), colour)
+    }
+
+    /// Render indeterminate progress bar animation with opening and closing brackets.
+    pub fn fmt_render_indeterminate(
+        &self,
+        ncols: NonZeroU16,
+        elapsed_time: f32,
+        colour: &Option<Colour>,
+    ) -> String {
+        self.fmt_brackets(self.render_indeterminate(ncols, elapsed_time), colour)
+    }
+
+    fn fmt_brackets(&self, render: String, colour: &Option<Colour>) -> String {
         let (bar_open, bar_close) = match self {
             Self::Arrow | Self::Classic => ("[", "]"),
             Self::FiraCode => (" ", ""),
             _ => ("|", "|"),
         };
 
-        let render = self.render(ncols, progress);
-
         if let Some(colour) = colour {
             bar_open.to_owned() + &colour.apply(&render) + bar_close
         } else {
@@ -152,15 +358,44 @@ impl From<&str> for Animation {
     fn from(animation: &str) -> Self {
         match animation.to_lowercase().as_str() {
             "arrow" => Self::Arrow,
+            "bounce" => Self::Bounce,
             "classic" => Self::Classic,
             "fillup" => Self::FillUp,
             "firacode" => Self::FiraCode,
+            "knightrider" => Self::KnightRider,
+            "pulse" => Self::Pulse,
+            "segmented" => Self::Segmented,
             "ascii" => Self::TqdmAscii,
             _ => Self::Tqdm,
         }
     }
 }
 
+/// Named part of progress counter, like passed, failed or skipped tests.
+///
+/// See [update_segment](crate::Bar::update_segment) for an example.
+#[derive(Clone, Debug)]
+pub struct Segment {
+    /// Name of segment, which is also used by `{segment name=...}` template placeholder.
+    pub name: String,
+    /// Style of segment drawn by [Animation::Segmented](Animation::Segmented) (e.g. "green", "#00ff00").
+    /// An empty style means no style is applied.
+    pub colour: String,
+    /// Counter value of segment.
+    pub count: usize,
+}
+
+impl Segment {
+    /// Create a new [Segment](Self) with zero count.
+    pub fn new<T: Into<String>, U: Into<String>>(name: T, colour: U) -> Self {
+        Self {
+            name: name.into(),
+            colour: colour.into(),
+            count: 0,
+        }
+    }
+}
+
 /// Colour applicable to text.
 #[derive(Debug, Clone)]
 pub enum Colour {
@@ -262,3 +497,92 @@ impl From<&str> for Colour {
         Self::Solid(value)
     }
 }
+
+/// Scrolling (marquee) text animation for displaying overlong texts within a fixed width.
+///
+/// # Example
+///
+/// ```
+/// use kdam::Marquee;
+///
+/// let marquee = Marquee::new(10, 10.0);
+///
+/// assert_eq!(marquee.render("/home/user/videos/movie.mkv", 0.0), "/home/user");
+/// assert_eq!(marquee.render("/home/user/videos/movie.mkv", 1.0), "/videos/mo");
+/// assert_eq!(marquee.render("movie.mkv", 1.0), "movie.mkv");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Marquee {
+    /// Number of columns to display.
+    pub width: u16,
+    /// Number of columns to scroll per second.
+    pub speed: f32,
+}
+
+impl Marquee {
+    /// Create a new [Marquee](Self).
+    pub fn new(width: u16, speed: f32) -> Self {
+        Self { width, speed }
+    }
+
+    /// Render scrolled text at given elapsed time.
+    ///
+    /// Text is only scrolled if it is wider than [width](Self::width).
+    /// ANSI escape codes are trimmed from scrolled text.
+    pub fn render(&self, text: &str, elapsed_time: f32) -> String {
+        let width = self.width as usize;
+
+        if text.len_ansi() <= width {
+            return text.to_owned();
+        }
+
+        let text = text.trim_ansi() + "   ";
+        let graphemes = utils::graphemes(&text).collect::<Vec<&str>>();
+        let offset = (elapsed_time * self.speed) as usize % graphemes.len();
+        let mut scrolled = String::new();
+        let mut scrolled_width = 0;
+
+        for grapheme in graphemes.iter().cycle().skip(offset) {
+            let grapheme_width = utils::len(grapheme);
+
+            if scrolled_width + grapheme_width > width {
+                break;
+            }
+
+            scrolled += grapheme;
+            scrolled_width += grapheme_width;
+        }
+
+        scrolled + &" ".repeat(width - scrolled_width)
+    }
+}
+
+/// Render a sparkline of `width` columns from last values, where each value is scaled between `0` and maximum value.
+///
+/// If there are less values than `width`, sparkline is padded with spaces from left.
+pub(super) fn render_sparkline<'a, T: DoubleEndedIterator<Item = &'a f32> + Clone>(
+    values: T,
+    width: usize,
+) -> String {
+    let max = values
+        .clone()
+        .rev()
+        .take(width)
+        .fold(0_f32, |a, &b| a.max(b));
+    let mut sparkline = values
+        .rev()
+        .take(width)
+        .map(|value| {
+            let index = if max > 0.0 {
+                (value / max * (BAR_FILLUP.len() - 1) as f32).round() as usize
+            } else {
+                0
+            };
+            BAR_FILLUP[index.min(BAR_FILLUP.len() - 1)]
+        })
+        .collect::<Vec<_>>();
+
+    sparkline.resize(width, " ");
+    sparkline.reverse();
+    sparkline.concat()
+}
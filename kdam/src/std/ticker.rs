@@ -0,0 +1,202 @@
+use super::{BarExt, derive};
+use crate::term::Colorizer;
+use std::{
+    fmt,
+    io::Result,
+    sync::{
+        Arc, Mutex, Weak,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Progress bar which can be redrawn by [SteadyTick](crate::SteadyTick) thread.
+///
+/// It is implemented for [Bar](crate::Bar), [RichProgress](crate::RichProgress),
+/// [RowManager](crate::RowManager) and all types deriving [BarExt](crate::BarExt).
+pub trait Tick {
+    /// Redraw progress bar without calling any callbacks.
+    /// Returns `false` if progress bar shouldn't be redrawn anymore.
+    fn tick(&mut self) -> bool;
+
+    /// Start redrawing shared progress bar every `interval` using [SteadyTick](crate::SteadyTick).
+    fn enable_steady_tick(pb: &Arc<Mutex<Self>>, interval: Duration) -> SteadyTick
+    where
+        Self: Sized + Send + 'static,
+    {
+        SteadyTick::new(pb, interval)
+    }
+}
+
+impl<T: derive::Hooks + BarExt> Tick for T {
+    fn tick(&mut self) -> bool {
+        let pb = self.bar();
+
+        if pb.disable || pb.completed() {
+            return false;
+        }
+
+        redraw(self).is_ok()
+    }
+}
+
+/// Redraw progress bar like [refresh](crate::BarExt::refresh), without calling any callbacks.
+fn redraw<T: derive::Hooks>(this: &mut T) -> Result<()> {
+    this.bar().elapsed_time();
+    let text = derive::Hooks::render(this);
+    let pb = this.bar();
+    let bar_length = text.len_ansi() as u16;
+
+    if bar_length > pb.bar_length {
+        BarExt::clear(pb)?;
+        pb.bar_length = bar_length;
+    }
+
+    let escape_codes = pb.terminal_escape_codes();
+    pb.writer
+        .print_at(pb.position, (escape_codes + &text).as_bytes())
+}
+
+struct Slot {
+    active: AtomicBool,
+    interval: Duration,
+    last_refresh: Mutex<Instant>,
+    pb: Weak<Mutex<dyn Tick + Send>>,
+}
+
+struct Slots {
+    running: bool,
+    slots: Vec<Arc<Slot>>,
+}
+
+/// Slots of all progress bars with steady tick enabled, shared by single ticker thread.
+static SLOTS: Mutex<Slots> = Mutex::new(Slots {
+    running: false,
+    slots: Vec::new(),
+});
+
+/// Redraw a shared progress bar every `interval` from a background thread, so that spinners,
+/// indeterminate animations and elapsed time keep moving even if no updates arrive.
+///
+/// A single background thread is shared by all progress bars.
+/// Progress bar is locked while it is redrawn and callbacks such as [on_refresh](crate::Bar::on_refresh)
+/// are never called from background thread.
+/// Ticking is stopped once progress is completed, progress bar is dropped or this handle is dropped.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, SteadyTick};
+/// use std::{
+///     sync::{Arc, Mutex},
+///     time::Duration,
+/// };
+///
+/// let pb = Arc::new(Mutex::new(tqdm!(total = 10, spinner = "dots", desc = "Working")));
+/// let steady_tick = SteadyTick::new(&pb, Duration::from_millis(80));
+///
+/// for _ in 0..10 {
+///     std::thread::sleep(Duration::from_millis(10));
+///     pb.lock().unwrap().update(1).unwrap();
+/// }
+///
+/// drop(steady_tick);
+/// eprintln!();
+/// ```
+#[must_use = "ticking is stopped when handle is dropped"]
+pub struct SteadyTick(Arc<Slot>);
+
+/// Shortest interval between redraws, so that ticker thread never busy loops.
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+impl SteadyTick {
+    /// Start redrawing progress bar every `interval`.
+    /// Interval shorter than 1ms (e.g. [Duration::ZERO]) is clamped to 1ms.
+    pub fn new<T: Tick + Send + 'static>(pb: &Arc<Mutex<T>>, interval: Duration) -> Self {
+        let pb: Arc<Mutex<dyn Tick + Send>> = pb.clone();
+        let slot = Arc::new(Slot {
+            active: AtomicBool::new(true),
+            interval: interval.max(MIN_INTERVAL),
+            last_refresh: Mutex::new(Instant::now()),
+            pb: Arc::downgrade(&pb),
+        });
+
+        let mut slots = SLOTS.lock().unwrap();
+        slots.slots.push(slot.clone());
+
+        // Ticker thread exits when there are no slots left, so a new one is spawned.
+        if !slots.running {
+            slots.running = true;
+            thread::spawn(run);
+        }
+
+        Self(slot)
+    }
+
+    /// Returns `true` if progress bar is still being redrawn.
+    pub fn is_active(&self) -> bool {
+        self.0.active.load(Ordering::Acquire) && self.0.pb.strong_count() > 0
+    }
+}
+
+impl Drop for SteadyTick {
+    fn drop(&mut self) {
+        self.0.active.store(false, Ordering::Release);
+        SLOTS
+            .lock()
+            .unwrap()
+            .slots
+            .retain(|slot| !Arc::ptr_eq(slot, &self.0));
+    }
+}
+
+impl fmt::Debug for SteadyTick {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SteadyTick")
+            .field("interval", &self.0.interval)
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+fn run() {
+    loop {
+        let slots = {
+            let mut slots = SLOTS.lock().unwrap();
+            slots
+                .slots
+                .retain(|slot| slot.active.load(Ordering::Acquire) && slot.pb.strong_count() > 0);
+
+            if slots.slots.is_empty() {
+                slots.running = false;
+                return;
+            }
+
+            slots.slots.clone()
+        };
+
+        let mut sleep = Duration::from_millis(100);
+
+        for slot in slots {
+            let mut last_refresh = slot.last_refresh.lock().unwrap();
+
+            // Handle may be dropped while waiting for lock of progress bar.
+            if last_refresh.elapsed() >= slot.interval {
+                if let Some(pb_arc) = slot.pb.upgrade()
+                    && let Ok(mut pb) = pb_arc.lock()
+                    && slot.active.load(Ordering::Acquire)
+                    && !pb.tick()
+                {
+                    slot.active.store(false, Ordering::Release);
+                }
+
+                *last_refresh = Instant::now();
+            }
+
+            sleep = sleep.min(slot.interval.saturating_sub(last_refresh.elapsed()));
+        }
+
+        thread::sleep(sleep);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::term::{self, Colorizer};
+
+/// Parts of progress bar display, which can be truncated to fit progress bar within terminal width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Truncate {
+    /// Progress bar description i.e. [desc](crate::Bar::desc).
+    Desc,
+    /// Progress bar postfix i.e. [postfix](crate::Bar::postfix).
+    Postfix,
+    /// [Column::Text](crate::Column::Text) at given index, only used by [RichProgress](crate::RichProgress).
+    Column(usize),
+}
+
+impl Truncate {
+    /// Default truncation priority of [Bar](crate::Bar).
+    pub(crate) fn defaults() -> Vec<Self> {
+        vec![Self::Postfix, Self::Desc]
+    }
+}
+
+/// Truncate texts in order of `priorities`, so that a display of `length` width fits within terminal width.
+///
+/// Returns whether any text was truncated or not.
+pub(super) fn fit(
+    priorities: &[Truncate],
+    length: usize,
+    texts: &mut [(Truncate, String)],
+) -> bool {
+    let mut overflow = match term::width() {
+        Some(width) => length.saturating_sub(width as usize),
+        None => 0,
+    };
+
+    if overflow == 0 {
+        return false;
+    }
+
+    for priority in priorities {
+        for (part, text) in texts.iter_mut() {
+            if part == priority && overflow > 0 {
+                let text_length = text.len_ansi();
+                let truncated = text.truncate_ansi(text_length.saturating_sub(overflow));
+                overflow = overflow.saturating_sub(text_length - truncated.len_ansi());
+                *text = truncated;
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,163 @@
+/*
+    REFERENCES:
+    ----------
+
+    1. https://vt100.net/emu/dec_ansi_parser
+    2. https://www.ecma-international.org/publications-and-standards/standards/ecma-48
+
+*/
+
+use crate::utils;
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    Csi,
+    // OSC, DCS, SOS, PM and APC strings terminated by BEL or ST.
+    String,
+    StringEscape,
+}
+
+/// Strip ANSI escape sequences (CSI, OSC, DCS, etc.) from text.
+///
+/// Returns stripped text and its display width.
+/// Unterminated escape sequences are dropped till end of text.
+///
+/// # Example
+///
+/// ```
+/// use kdam::term::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[31;1mred\x1b[0m"), ("red".to_owned(), 3));
+/// assert_eq!(
+///     strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"),
+///     ("link".to_owned(), 4)
+/// );
+/// assert_eq!(strip_ansi("up\x1b[2Adown\x1b[?25l"), ("updown".to_owned(), 6));
+/// assert_eq!(strip_ansi("unterminated\x1b[31"), ("unterminated".to_owned(), 12));
+/// ```
+pub fn strip_ansi(text: &str) -> (String, usize) {
+    let mut stripped = String::with_capacity(text.len());
+    let mut width = 0;
+
+    parse(text, |segment, escape| {
+        if !escape {
+            stripped.push_str(segment);
+            width += utils::len(segment);
+        }
+    });
+
+    (stripped, width)
+}
+
+/// Truncate text to given display width, keeping escape sequences intact.
+/// If text is truncated, last column is replaced with `…`.
+pub(super) fn truncate_ansi(text: &str, width: usize) -> String {
+    if strip_ansi(text).1 <= width {
+        return text.to_owned();
+    }
+
+    let limit = width.saturating_sub(1);
+    let mut truncated = String::with_capacity(text.len());
+    let mut current_width = 0;
+    let mut done = false;
+
+    parse(text, |segment, escape| {
+        if escape {
+            truncated.push_str(segment);
+            return;
+        }
+
+        if done {
+            return;
+        }
+
+        for grapheme in utils::graphemes(segment) {
+            let grapheme_width = utils::len(grapheme);
+
+            if current_width + grapheme_width > limit {
+                if width > 0 {
+                    truncated.push('…');
+                }
+
+                done = true;
+                break;
+            }
+
+            truncated.push_str(grapheme);
+            current_width += grapheme_width;
+        }
+    });
+
+    truncated
+}
+
+/// Split text into printable segments and escape sequences.
+///
+/// Callback is called with segment and whether segment is an escape sequence or not.
+fn parse<'a>(text: &'a str, mut callback: impl FnMut(&'a str, bool)) {
+    let mut state = State::Ground;
+    let mut run_start = 0;
+
+    for (index, character) in text.char_indices() {
+        if state == State::Ground {
+            let next_state = match character {
+                '\x1b' => State::Escape,
+                '\u{9b}' => State::Csi,
+                '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => State::String,
+                _ => continue,
+            };
+
+            if run_start < index {
+                callback(&text[run_start..index], false);
+            }
+
+            run_start = index;
+            state = next_state;
+            continue;
+        }
+
+        if state == State::StringEscape {
+            // ESC not followed by '\\' aborts string and starts a new escape sequence.
+            if character == '\\' {
+                state = State::Ground;
+            } else {
+                callback(&text[run_start..(index - 1)], true);
+                run_start = index - 1;
+                state = State::Escape;
+            }
+        }
+
+        if state != State::Ground {
+            state = match (state, character) {
+                (State::String, '\x1b') => State::StringEscape,
+                (State::String, '\x07' | '\u{9c}') => State::Ground,
+                (State::String, _) => State::String,
+                (_, '\x1b') => {
+                    callback(&text[run_start..index], true);
+                    run_start = index;
+                    State::Escape
+                }
+                (State::Escape, '[') => State::Csi,
+                (State::Escape, ']' | 'P' | 'X' | '^' | '_') => State::String,
+                (State::Escape | State::EscapeIntermediate, '\x20'..='\x2f') => {
+                    State::EscapeIntermediate
+                }
+                (State::Csi, '\x20'..='\x3f') => State::Csi,
+                _ => State::Ground,
+            };
+        }
+
+        if state == State::Ground {
+            let end = index + character.len_utf8();
+            callback(&text[run_start..end], true);
+            run_start = end;
+        }
+    }
+
+    if state == State::Ground && run_start < text.len() {
+        callback(&text[run_start..], false);
+    }
+}
@@ -7,8 +7,8 @@
 
 */
 
-use crate::utils;
-use std::sync::atomic::{AtomicBool, Ordering};
+use super::{ansi, markup, strip_ansi};
+use std::sync::atomic::{AtomicU8, Ordering};
 
 #[cfg(windows)]
 use windows_sys::Win32::System::Console::{
@@ -29,23 +29,103 @@ const COLOUR_ATTRIBUTES: [&str; 8] = [
     "hidden",
     "strikethrough",
 ];
-const COLOUR_RESET: &str = "\x1b[0m";
+pub(super) const COLOUR_RESET: &str = "\x1b[0m";
+const COLOUR_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+const COLOUR_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+static COLOUR_SUPPORT: AtomicU8 = AtomicU8::new(ColourSupport::None as u8);
+
+/// Colour capability of a terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColourSupport {
+    /// No colours, escape codes are not emitted.
+    None = 0,
+    /// 16 colours (4-bit).
+    Ansi16 = 1,
+    /// 256 colours (8-bit).
+    Ansi256 = 2,
+    /// True colours (24-bit).
+    TrueColour = 3,
+}
 
-static SHOULD_COLORIZE: AtomicBool = AtomicBool::new(false);
+impl ColourSupport {
+    /// Detect colour capability of terminal using `COLORTERM` and `TERM` environment variables.
+    ///
+    /// On windows, true colours are assumed when `TERM` is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::ColourSupport;
+    ///
+    /// println!("{:?}", ColourSupport::detect());
+    /// ```
+    pub fn detect() -> Self {
+        if let Ok(colorterm) = std::env::var("COLORTERM") {
+            let colorterm = colorterm.to_lowercase();
+
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return Self::TrueColour;
+            }
+        }
+
+        if std::env::var("WT_SESSION").is_ok() {
+            return Self::TrueColour;
+        }
+
+        match std::env::var("TERM").map(|x| x.to_lowercase()) {
+            Ok(term) if term == "dumb" => Self::None,
+            Ok(term) if term.contains("truecolor") || term.contains("24bit") => Self::TrueColour,
+            Ok(term) if term.contains("256") => Self::Ansi256,
+            Err(_) if cfg!(windows) => Self::TrueColour,
+            _ => Self::Ansi16,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Ansi16,
+            2 => Self::Ansi256,
+            3 => Self::TrueColour,
+            _ => Self::None,
+        }
+    }
+}
 
 /// Enable/Disable colorization property of [colorizer](crate::term::Colorizer) trait.
-/// 
+///
 /// Colorization is **disabled** by default.
-/// 
+/// When enabled, colour capability of terminal is detected using [ColourSupport::detect](crate::term::ColourSupport::detect)
+/// and hex, rgb(r,g,b) and ansi(n) colours are downgraded to the nearest supported colour.
+/// At least 16 colours are used when enabled, even if `TERM=dumb` is set.
+/// Use [set_colour_support](crate::term::set_colour_support) to override detected capability.
+///
 /// # Platform-specific notes
-/// 
+///
 /// This functions also enables support for ANSI escape codes on windows.
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use std::io::{stderr, IsTerminal};
-/// 
+///
 /// kdam::term::init(stderr().is_terminal());
 /// ```
 pub fn init(always: bool) {
@@ -63,7 +143,127 @@ pub fn init(always: bool) {
         }
     }
 
-    SHOULD_COLORIZE.store(always, Ordering::SeqCst);
+    set_colour_support(if always {
+        ColourSupport::detect().max(ColourSupport::Ansi16)
+    } else {
+        ColourSupport::None
+    });
+}
+
+/// Returns colour capability used by [colorizer](crate::term::Colorizer) trait.
+pub fn colour_support() -> ColourSupport {
+    ColourSupport::from_u8(COLOUR_SUPPORT.load(Ordering::Acquire))
+}
+
+/// Set colour capability used by [colorizer](crate::term::Colorizer) trait.
+///
+/// # Example
+///
+/// ```
+/// use kdam::term::{self, Colorizer, ColourSupport};
+///
+/// term::set_colour_support(ColourSupport::Ansi256);
+/// assert_eq!("kdam".colorize("#ff0000"), "\x1b[38;5;196mkdam\x1b[0m");
+///
+/// term::set_colour_support(ColourSupport::Ansi16);
+/// assert_eq!("kdam".colorize("#ff0000"), "\x1b[91mkdam\x1b[0m");
+/// ```
+pub fn set_colour_support(support: ColourSupport) {
+    COLOUR_SUPPORT.store(support as u8, Ordering::SeqCst);
+}
+
+fn colour_distance(x: (u8, u8, u8), y: (u8, u8, u8)) -> u32 {
+    let dr = x.0 as i32 - y.0 as i32;
+    let dg = x.1 as i32 - y.1 as i32;
+    let db = x.2 as i32 - y.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+// (255, 0, 0) -> 196
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |x: u8| match x {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (x - 35) / 40,
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (
+        COLOUR_CUBE_LEVELS[ri as usize],
+        COLOUR_CUBE_LEVELS[gi as usize],
+        COLOUR_CUBE_LEVELS[bi as usize],
+    );
+
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let grey_index = if average > 238 {
+        23
+    } else {
+        (average.saturating_sub(3) / 10) as u8
+    };
+    let grey = 8 + 10 * grey_index;
+
+    if colour_distance((grey, grey, grey), (r, g, b)) < colour_distance(cube, (r, g, b)) {
+        232 + grey_index
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+// 196 -> (255, 0, 0)
+fn ansi256_to_rgb(number: u8) -> (u8, u8, u8) {
+    match number {
+        0..=15 => COLOUR_PALETTE[number as usize],
+        16..=231 => {
+            let number = number - 16;
+            (
+                COLOUR_CUBE_LEVELS[(number / 36) as usize],
+                COLOUR_CUBE_LEVELS[((number % 36) / 6) as usize],
+                COLOUR_CUBE_LEVELS[(number % 6) as usize],
+            )
+        }
+        _ => {
+            let grey = 8 + 10 * (number - 232);
+            (grey, grey, grey)
+        }
+    }
+}
+
+// (255, 0, 0) -> 9
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    (0..16_u8)
+        .min_by_key(|x| colour_distance(COLOUR_PALETTE[*x as usize], (r, g, b)))
+        .unwrap()
+}
+
+// 9, false -> 91
+fn ansi16_code(number: u8, background: bool) -> String {
+    let offset = if background { 10 } else { 0 };
+
+    if number < 8 {
+        (30 + offset + number).to_string()
+    } else {
+        (90 + offset + number - 8).to_string()
+    }
+}
+
+// 255, 0, 0, false -> 38;2;255;0;0
+fn rgb_code(r: u8, g: u8, b: u8, background: bool) -> String {
+    let layer = if background { 48 } else { 38 };
+
+    match colour_support() {
+        ColourSupport::Ansi256 => format!("{};5;{}", layer, rgb_to_ansi256(r, g, b)),
+        ColourSupport::Ansi16 => ansi16_code(rgb_to_ansi16(r, g, b), background),
+        _ => format!("{};2;{};{};{}", layer, r, g, b),
+    }
+}
+
+// 196, false -> 38;5;196
+fn ansi256_code(number: u8, background: bool) -> String {
+    if colour_support() == ColourSupport::Ansi16 {
+        let (r, g, b) = ansi256_to_rgb(number);
+        ansi16_code(rgb_to_ansi16(r, g, b), background)
+    } else {
+        format!("{};5;{}", if background { 48 } else { 38 }, number)
+    }
 }
 
 // #FFFFFF -> Some((255, 255, 255))
@@ -79,6 +279,24 @@ fn hex_to_rgb(code: &str) -> Option<(u8, u8, u8)> {
     }
 }
 
+// "bold red on #00ff00" -> true, "shredded" -> false
+pub(super) fn is_colour(code: &str) -> bool {
+    let code = code.to_lowercase();
+    let is_style = |word: &str| {
+        COLOURS.contains(&word)
+            || COLOUR_ATTRIBUTES.contains(&word)
+            || (word.starts_with('#') && hex_to_rgb(word).is_some())
+            || (word.starts_with("rgb(") && word.ends_with(')') && parse_rgb(word).is_some())
+            || (word.starts_with("ansi(") && word.ends_with(')') && parse_ansi(word).is_some())
+    };
+
+    // Modifiers like "bright" and "on" are not a style by themselves.
+    code.split_whitespace().any(is_style)
+        && code
+            .split_whitespace()
+            .all(|word| word == "bright" || word == "on" || is_style(word))
+}
+
 // ansi(15) -> Some(15)
 fn parse_ansi(code: &str) -> Option<u8> {
     code.get(5..(code.len() - 1))?.parse::<u8>().ok()
@@ -95,6 +313,8 @@ fn parse_rgb(code: &str) -> Option<(u8, u8, u8)> {
 
 /// Create ANSI colour escape code from primary colours, hex code, rgb(r,g,b) and ansi(n).
 ///
+/// Hex code, rgb(r,g,b) and ansi(n) colours are downgraded according to [colour_support](crate::term::colour_support).
+///
 /// # Example
 ///
 /// ```
@@ -111,17 +331,17 @@ pub fn colour(code: &str) -> Option<String> {
         let end = 3 + 7;
         let (r, g, b) = hex_to_rgb(code.get((index + 3)..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        bg = Some(format!("48;2;{};{};{}", r, g, b));
+        bg = Some(rgb_code(r, g, b, true));
     } else if let Some(index) = code.find("on rgb(") {
         let end = 3 + code.get((index + 3)..)?.find(')')? + 1;
         let (r, g, b) = parse_rgb(code.get((index + 3)..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        bg = Some(format!("48;2;{};{};{}", r, g, b));
+        bg = Some(rgb_code(r, g, b, true));
     } else if let Some(index) = code.find("on ansi(") {
         let end = 4 + code.get((index + 4)..)?.find(')')? + 1;
         let number = parse_ansi(code.get((index + 3)..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        bg = Some(format!("48;5;{}", number));
+        bg = Some(ansi256_code(number, true));
     } else {
         let mut number = 100_u8;
 
@@ -160,17 +380,17 @@ pub fn colour(code: &str) -> Option<String> {
         let end = 7;
         let (r, g, b) = hex_to_rgb(code.get(index..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        fg = Some(format!("38;2;{};{};{}", r, g, b));
+        fg = Some(rgb_code(r, g, b, false));
     } else if let Some(index) = code.find("rgb(") {
         let end = code.get(index..)?.find(')')? + 1;
         let (r, g, b) = parse_rgb(code.get(index..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        fg = Some(format!("38;2;{};{};{}", r, g, b));
+        fg = Some(rgb_code(r, g, b, false));
     } else if let Some(index) = code.find("ansi(") {
         let end = code.get(index..)?.find(')')? + 1;
         let number = parse_ansi(code.get(index..(index + end))?)?;
         code.replace_range(index..(index + end), "");
-        fg = Some(format!("38;5;{}", number));
+        fg = Some(ansi256_code(number, false));
     } else {
         let mut number = 90_u8;
 
@@ -202,9 +422,8 @@ pub fn colour(code: &str) -> Option<String> {
     }
 
     let mut attributes = String::new();
-    let mut number = 1_u8;
 
-    for attribute in COLOUR_ATTRIBUTES {
+    for (number, attribute) in (1_u8..).zip(COLOUR_ATTRIBUTES) {
         if let Some(index) = code.find(attribute) {
             code.replace_range(index..(index + attribute.len()), "");
 
@@ -214,8 +433,6 @@ pub fn colour(code: &str) -> Option<String> {
 
             attributes += &number.to_string();
         }
-
-        number += 1;
     }
 
     let attributes = if attributes.is_empty() {
@@ -271,17 +488,73 @@ pub trait Colorizer {
     /// ```
     fn colorize(&self, code: &str) -> String;
 
+    /// Wrap the given text into a clickable hyperlink using `OSC 8` escape sequence.
+    ///
+    /// Like [colorize](Self::colorize), escape codes are only added if colorization is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::Colorizer;
+    ///
+    /// let link = "docs".hyperlink("https://docs.rs/kdam");
+    /// println!("{}", link);
+    /// assert_eq!(link.len_ansi(), 4);
+    /// ```
+    fn hyperlink(&self, url: &str) -> String;
+
     /// Inverse of colorize method.
-    /// This method trims all ANSI escape codes from given string.
+    /// This method trims all ANSI escape codes (CSI, OSC, etc.) from given string.
+    ///
+    /// See [strip_ansi](crate::term::strip_ansi) for more details.
     fn trim_ansi(&self) -> String;
 
-    /// Returns display length of string by triming ANSI escape codes.
+    /// Returns display length of string by triming ANSI escape codes (CSI, OSC, etc.).
     fn len_ansi(&self) -> usize;
+
+    /// Truncate string to given display width keeping ANSI escape codes intact.
+    /// If string is truncated, last column is replaced with `…`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::Colorizer;
+    ///
+    /// assert_eq!("hello world!".truncate_ansi(6), "hello…");
+    /// assert_eq!("\x1b[31mhello\x1b[0m".truncate_ansi(3), "\x1b[31mhe…\x1b[0m");
+    /// assert_eq!("hello".truncate_ansi(5), "hello");
+    /// ```
+    fn truncate_ansi(&self, width: usize) -> String;
+
+    /// Render rich style markup, like `[bold red]Error[/] processing [cyan]main.rs[/cyan]`, into ANSI escape codes.
+    ///
+    /// - `[style]` opens a style, where style is a [colour](crate::term::colour) code.
+    ///   Styles can be nested and unclosed styles last till end of text.
+    /// - `[/]` closes last opened style and `[/style]` closes last opened matching style.
+    /// - `\[` is rendered as literal `[`.
+    /// - Brackets which are not valid tags (e.g. `[1/3]`) are kept as it is.
+    ///
+    /// Like [colorize](Self::colorize), escape codes are only added if colorization is enabled else only tags are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::Colorizer;
+    ///
+    /// let text = "[bold red]Error[/] processing [cyan]main.rs[/cyan]".markup();
+    /// println!("{}", text);
+    /// assert_eq!(text.trim_ansi(), "Error processing main.rs");
+    /// assert_eq!(text.len_ansi(), 24);
+    ///
+    /// assert_eq!("[bold][1/3] \\[italic] done".markup().trim_ansi(), "[1/3] [italic] done");
+    /// assert_eq!("[on]x".markup(), "[on]x");
+    /// ```
+    fn markup(&self) -> String;
 }
 
 impl Colorizer for str {
     fn colorize(&self, code: &str) -> String {
-        if !SHOULD_COLORIZE.load(Ordering::Acquire) {
+        if colour_support() == ColourSupport::None {
             return self.to_owned();
         }
 
@@ -294,19 +567,27 @@ impl Colorizer for str {
         }
     }
 
-    fn trim_ansi(&self) -> String {
-        let mut text = self.to_owned();
-
-        while let Some(start) = text.find("\x1b[") {
-            if let Some(end) = text[start..].find('m') {
-                text.replace_range(start..(start + end + 1), "");
-            }
+    fn hyperlink(&self, url: &str) -> String {
+        if colour_support() == ColourSupport::None {
+            return self.to_owned();
         }
 
-        text
+        format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, self)
+    }
+
+    fn trim_ansi(&self) -> String {
+        strip_ansi(self).0
     }
 
     fn len_ansi(&self) -> usize {
-        utils::len(&self.trim_ansi())
+        strip_ansi(self).1
+    }
+
+    fn truncate_ansi(&self, width: usize) -> String {
+        ansi::truncate_ansi(self, width)
+    }
+
+    fn markup(&self) -> String {
+        markup::render(self)
     }
 }
@@ -0,0 +1,119 @@
+/*
+    REFERENCES:
+    ----------
+
+    1. https://rich.readthedocs.io/en/latest/markup.html
+
+*/
+
+use super::colours::{self, COLOUR_RESET, ColourSupport, colour, colour_support};
+
+struct Renderer<'a> {
+    text: String,
+    styles: Vec<&'a str>,
+    active: bool,
+    pending: bool,
+    styled: bool,
+}
+
+impl Renderer<'_> {
+    fn push(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        // Styles are only emitted before text, so that empty styled regions don't leave escape codes.
+        if self.pending && self.styled {
+            if self.active {
+                self.text.push_str(COLOUR_RESET);
+            }
+
+            for style in self.styles.iter() {
+                self.text.push_str(&colour(style).unwrap_or_default());
+            }
+
+            self.active = !self.styles.is_empty();
+            self.pending = false;
+        }
+
+        self.text.push_str(text);
+    }
+
+    // [/bold red] -> true, if "bold red" is opened
+    fn close(&mut self, style: &str) -> bool {
+        let style = style.trim();
+        let position = if style.is_empty() {
+            self.styles.len().checked_sub(1)
+        } else {
+            self.styles
+                .iter()
+                .rposition(|x| x.trim().eq_ignore_ascii_case(style))
+        };
+
+        if let Some(position) = position {
+            self.styles.remove(position);
+            self.pending = true;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Render rich style markup into ANSI escape codes.
+pub(super) fn render(text: &str) -> String {
+    let mut renderer = Renderer {
+        text: String::with_capacity(text.len()),
+        styles: vec![],
+        active: false,
+        pending: false,
+        styled: colour_support() != ColourSupport::None,
+    };
+    let mut rest = text;
+
+    while let Some(index) = rest.find(['[', '\\']) {
+        let (before, after) = rest.split_at(index);
+        renderer.push(before);
+
+        if let Some(after) = after.strip_prefix("\\[") {
+            renderer.push("[");
+            rest = after;
+            continue;
+        }
+
+        if let Some(after) = after.strip_prefix('\\') {
+            renderer.push("\\");
+            rest = after;
+            continue;
+        }
+
+        if let Some(end) = after.find(']') {
+            let tag = &after[1..end];
+            let is_tag = if let Some(style) = tag.strip_prefix('/') {
+                renderer.close(style)
+            } else if colours::is_colour(tag) {
+                renderer.styles.push(tag);
+                renderer.pending = true;
+                true
+            } else {
+                false
+            };
+
+            if is_tag {
+                rest = &after[(end + 1)..];
+                continue;
+            }
+        }
+
+        renderer.push("[");
+        rest = &after[1..];
+    }
+
+    renderer.push(rest);
+
+    if renderer.active {
+        renderer.text.push_str(COLOUR_RESET);
+    }
+
+    renderer.text
+}
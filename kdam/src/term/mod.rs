@@ -2,10 +2,15 @@
 
 use std::io::{stderr, Result, Write};
 
+mod ansi;
 mod colours;
+mod markup;
+mod osc;
 mod writer;
 
-pub use colours::{colour, init, Colorizer};
+pub use ansi::strip_ansi;
+pub use colours::{colour, colour_support, init, set_colour_support, Colorizer, ColourSupport};
+pub use osc::TaskbarState;
 pub use writer::{InitializedOutput, Writer};
 
 /// Hide cursor.
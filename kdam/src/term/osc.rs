@@ -0,0 +1,54 @@
+/*
+    REFERENCES:
+    ----------
+
+    1. https://learn.microsoft.com/en-us/windows/terminal/tutorials/progress-bar-sequences
+    2. https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+
+*/
+
+/// Progress state displayed in terminal tab/taskbar using `OSC 9;4` escape sequence.
+///
+/// Supported by Windows Terminal, ConEmu, WezTerm and few other terminals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskbarState {
+    /// Remove progress from tab/taskbar.
+    Hidden,
+    /// Display progress normally.
+    Normal,
+    /// Display progress in error state.
+    Error,
+    /// Display an indeterminate progress.
+    Indeterminate,
+    /// Display progress in paused (warning) state.
+    Paused,
+}
+
+impl TaskbarState {
+    /// Create `OSC 9;4` escape code for this state.
+    ///
+    /// # Arguments
+    ///
+    /// - *progress*: Percentage done, it should be in range (0.0 - 1.0) inclusive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::TaskbarState;
+    ///
+    /// assert_eq!(TaskbarState::Normal.escape_code(0.5), "\x1b]9;4;1;50\x1b\\");
+    /// assert_eq!(TaskbarState::Hidden.escape_code(0.5), "\x1b]9;4;0;0\x1b\\");
+    /// ```
+    pub fn escape_code(&self, progress: f32) -> String {
+        let progress = (progress.clamp(0.0, 1.0) * 100.0) as u8;
+        let (state, progress) = match self {
+            Self::Hidden => (0, 0),
+            Self::Normal => (1, progress),
+            Self::Error => (2, progress),
+            Self::Indeterminate => (3, 0),
+            Self::Paused => (4, progress),
+        };
+
+        format!("\x1b]9;4;{};{}\x1b\\", state, progress)
+    }
+}
@@ -14,3 +14,16 @@ pub(super) fn len(text: &str) -> usize {
 pub(super) fn len(text: &str) -> usize {
     text.chars().count()
 }
+
+/// Returns an iterator over graphemes of the given text.
+#[cfg(feature = "unicode")]
+pub(super) fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+}
+
+/// Returns an iterator over characters of the given text.
+#[cfg(not(feature = "unicode"))]
+pub(super) fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    text.char_indices()
+        .map(|(index, character)| &text[index..(index + character.len_utf8())])
+}
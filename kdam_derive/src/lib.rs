@@ -1,29 +1,43 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, FieldsNamed, Meta, Path};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Error, Fields, Index,
+    LitStr, Member, Path, Result,
+};
 
 /// Derive [BarExt](https://docs.rs/kdam/latest/kdam/trait.BarExt.html) trait for a struct.
 ///
+/// One struct field of type `kdam::Bar` must use `#[bar]` attribute.
+/// Both named and tuple structs (with or without generics) are supported.
+///
+/// By default an inherent `render(&mut self) -> String` method is used for rendering progress bar text.
+/// This and other hooks can be customised with `#[bar_ext(...)]` struct attribute.
+/// A hook is either a method name or a path to a function which takes `&mut Self` as first argument.
+///
+/// - `render = "fn"`: `fn(&mut self) -> String` used for rendering progress bar text.
+/// - `on_update = "fn"`: `fn(&mut self, n: usize)` called after counter is updated by `n`.
+/// - `on_complete = "fn"`: `fn(&mut self)` called once when counter reaches total, before final refresh.
+///
 /// # Example
 ///
 /// ```no_test
 /// use kdam::{tqdm, Bar, BarExt};
 /// use std::{io::Result, num::NonZeroU16};
-/// 
+///
 /// #[derive(BarExt)]
 /// struct CustomBar {
 ///     #[bar]
 ///     pb: Bar,
 /// }
-/// 
+///
 /// impl CustomBar {
 ///     /// Render progress bar text.
 ///     fn render(&mut self) -> String {
 ///         let fmt_percentage = self.pb.fmt_percentage(0);
 ///         let padding = 1 + fmt_percentage.chars().count() as u16 + self.pb.animation.spaces() as u16;
-/// 
+///
 ///         let ncols = self.pb.ncols_for_animation(padding);
-/// 
+///
 ///         if ncols == 0 {
 ///             self.pb.bar_length = padding - 1;
 ///             fmt_percentage
@@ -39,34 +53,37 @@ use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, FieldsNamed,
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(BarExt, attributes(bar))]
+///
+/// Using hooks with a generic tuple struct.
+///
+/// ```no_test
+/// use kdam::{Bar, BarExt};
+///
+/// #[derive(BarExt)]
+/// #[bar_ext(render = "draw", on_complete = "done")]
+/// struct Labelled<T: std::fmt::Display>(#[bar] Bar, T);
+///
+/// impl<T: std::fmt::Display> Labelled<T> {
+///     fn draw(&mut self) -> String {
+///         format!("{}: {}", self.1, self.0.fmt_percentage(0))
+///     }
+///
+///     fn done(&mut self) {
+///         self.0.set_description("[green]done");
+///     }
+/// }
+/// ```
+#[proc_macro_derive(BarExt, attributes(bar, bar_ext))]
 pub fn bar_ext(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let mut bar_field = None;
-
-    if let Data::Struct(DataStruct {
-        fields: Fields::Named(FieldsNamed { named, .. }),
-        ..
-    }) = &input.data
-    {
-        for field in named {
-            for attr in &field.attrs {
-                if let Meta::Path(Path { segments, .. }) = &attr.meta {
-                    for segment in segments {
-                        if &segment.ident.to_string() == "bar" {
-                            bar_field = Some(field.ident.clone());
-                        }
-                    }
-                }
-            }
-        }
-    } else {
-        unimplemented!("BarExt derive macro is only derivable on structs.")
-    }
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
 
-    if bar_field.is_none() {
-        panic!("One struct field needs to use #[bar] attribute.")
-    }
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let bar_field = bar_field(input)?;
+    let hooks = Hooks::parse(input)?;
 
     let crate_name = if std::env::var("CARGO_CRATE_NAME")
         .expect("CARGO_CRATE_NAME env variable not set by cargo.")
@@ -77,113 +94,184 @@ pub fn bar_ext(input: TokenStream) -> TokenStream {
         "kdam"
     };
     let crate_name = format_ident!("{}", crate_name);
+    let helpers = quote!(#crate_name::__derive);
 
-    let bar_field = bar_field
-        .flatten()
-        .expect("#[bar] attribute struct field has not a valid identifier.");
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let expanded = quote! {
-        impl #impl_generics #crate_name::BarExt for #name #ty_generics #where_clause {
-            fn clear(&mut self) -> ::std::io::Result<()> {
-                self.#bar_field.clear()
+    let render = call(
+        &hooks.render.unwrap_or_else(|| parse_quote!(render)),
+        quote!(),
+    );
+    let on_update = hooks.on_update.map(|path| {
+        let call = call(&path, quote!(, n));
+        quote! {
+            fn on_update(&mut self, n: usize) {
+                #call
+            }
+        }
+    });
+    let on_complete = hooks.on_complete.map(|path| {
+        let call = call(&path, quote!());
+        quote! {
+            fn on_complete(&mut self) {
+                #call
             }
+        }
+    });
 
-            fn input<T: Into<String>>(&mut self, text: T) -> ::std::io::Result<String> {
-                self.clear()?;
-                self.#bar_field.writer.print(text.into().as_bytes())?;
+    Ok(quote! {
+        impl #impl_generics #helpers::Hooks for #name #ty_generics #where_clause {
+            fn bar(&mut self) -> &mut #crate_name::Bar {
+                &mut self.#bar_field
+            }
 
-                let mut buf = String::new();
-                ::std::io::stdin().read_line(&mut buf)?;
+            fn render(&mut self) -> String {
+                #render
+            }
 
-                if self.#bar_field.leave {
-                    self.refresh()?;
-                }
+            #on_update
+            #on_complete
+        }
 
-                Ok(buf)
+        impl #impl_generics #crate_name::BarExt for #name #ty_generics #where_clause {
+            fn clear(&mut self) -> ::std::io::Result<()> {
+                #helpers::clear(self)
             }
 
-            fn refresh(&mut self) -> ::std::io::Result<()> {
-                self.#bar_field.elapsed_time();
-
-                if self.#bar_field.completed() {
-                    if !self.#bar_field.leave && self.#bar_field.position > 0 {
-                        return self.clear();
-                    }
-        
-                    self.#bar_field.total = self.#bar_field.counter;
-                }
+            fn input<__KdamText: Into<String>>(&mut self, text: __KdamText) -> ::std::io::Result<String> {
+                #helpers::input(self, text)
+            }
 
-                let text = self.render();
-                let bar_length = #crate_name::term::Colorizer::len_ansi(text.as_str()) as u16;
-        
-                if bar_length > self.#bar_field.bar_length {
-                    self.clear()?;
-                    self.#bar_field.bar_length = bar_length;
-                }
-        
-                self.#bar_field.writer.print_at(self.#bar_field.position, text.as_bytes())?;
-                Ok(())
+            fn refresh(&mut self) -> ::std::io::Result<()> {
+                #helpers::refresh(self)
             }
 
             fn render(&mut self) -> String {
-                Self::render(self)
+                #helpers::Hooks::render(self)
             }
 
             fn reset(&mut self, total: Option<usize>) {
-                self.#bar_field.reset(total);
+                #helpers::reset(self, total)
             }
 
             fn update(&mut self, n: usize) -> ::std::io::Result<bool> {
-                self.#bar_field.counter += n;
-                let should_refresh = self.#bar_field.should_refresh();
+                #helpers::update(self, n)
+            }
 
-                if should_refresh {
-                    self.refresh()?;
-                }
+            fn update_to(&mut self, n: usize) -> ::std::io::Result<bool> {
+                #helpers::update_to(self, n)
+            }
 
-                Ok(should_refresh)
+            fn write<__KdamText: Into<String>>(&mut self, text: __KdamText) -> ::std::io::Result<()> {
+                #helpers::write(self, text)
             }
 
-            fn update_to(&mut self, n: usize) -> ::std::io::Result<bool> {
-                self.#bar_field.counter = n;
-                self.update(0)
+            fn write_to<__KdamWriter: ::std::io::Write>(&mut self, writer: &mut __KdamWriter, n: Option<usize>) -> ::std::io::Result<bool> {
+                #helpers::write_to(self, writer, n)
             }
+        }
+    })
+}
 
-            fn write<T: Into<String>>(&mut self, text: T) -> ::std::io::Result<()> {
-                self.#bar_field.clear()?;
-                self.#bar_field.writer.print(format!("\r{}\n", text.into()).as_bytes())?;
+/// Find struct field with `#[bar]` attribute.
+fn bar_field(input: &DeriveInput) -> Result<Member> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "BarExt derive macro is only derivable on structs.",
+            ))
+        }
+    };
 
-                if self.#bar_field.leave {
-                    self.refresh()?;
-                }
+    let mut bar_field = None;
 
-                Ok(())
+    for (index, field) in fields.iter().enumerate() {
+        if let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("bar")) {
+            if bar_field.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Only one struct field can use #[bar] attribute.",
+                ));
             }
 
-            fn write_to<T: ::std::io::Write>(&mut self, writer: &mut T, n: Option<usize>) -> ::std::io::Result<bool> {
-                let text;
+            bar_field = Some(match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index {
+                    index: index as u32,
+                    span: field.span(),
+                }),
+            });
+        }
+    }
+
+    bar_field.ok_or_else(|| {
+        let span = match fields {
+            Fields::Unit => input.ident.span(),
+            _ => fields.span(),
+        };
+        Error::new(span, "One struct field needs to use #[bar] attribute.")
+    })
+}
+
+/// Hooks specified using `#[bar_ext(...)]` attribute.
+#[derive(Default)]
+struct Hooks {
+    render: Option<Path>,
+    on_update: Option<Path>,
+    on_complete: Option<Path>,
+}
 
-                if let Some(n) = n {
-                    self.#bar_field.counter += n;
+impl Hooks {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut hooks = Self::default();
 
-                    if self.#bar_field.should_refresh() {
-                        text = #crate_name::term::Colorizer::trim_ansi(self.render().as_str());
-                    } else {
-                        return Ok(false);
-                    }
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("bar_ext"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let hook = if meta.path.is_ident("render") {
+                    &mut hooks.render
+                } else if meta.path.is_ident("on_update") {
+                    &mut hooks.on_update
+                } else if meta.path.is_ident("on_complete") {
+                    &mut hooks.on_complete
                 } else {
-                    text = #crate_name::term::Colorizer::trim_ansi(self.render().as_str());
+                    return Err(meta.error(
+                        "unknown bar_ext attribute, expected one of render, on_update or on_complete",
+                    ));
+                };
+
+                if hook.is_some() {
+                    return Err(meta.error("duplicate bar_ext attribute"));
                 }
 
-                self.#bar_field.bar_length = #crate_name::term::Colorizer::len_ansi(text.as_str()) as u16;
-                writer.write_all((text + "\n").as_bytes())?;
-                writer.flush()?;
-                Ok(true)
-            }
+                let value = meta.value()?;
+
+                *hook = Some(if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse()?
+                } else {
+                    value.parse()?
+                });
+
+                Ok(())
+            })?;
         }
-    };
 
-    TokenStream::from(expanded)
+        Ok(hooks)
+    }
+}
+
+/// Call a hook with `self` as first argument.
+///
+/// A single identifier is called as method of `Self`, any other path is called as a function.
+fn call(path: &Path, args: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match path.get_ident() {
+        Some(ident) => quote!(Self::#ident(self #args)),
+        None => quote!(#path(self #args)),
+    }
 }